@@ -0,0 +1,30 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use viater::WindDirectionMeasurements;
+
+fn add_measurements(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add_measurements");
+    for size in [1_000, 100_000] {
+        let values: Vec<f64> = (0..size).map(|i| (i % 360) as f64).collect();
+        group.bench_with_input(BenchmarkId::new("loop", size), &values, |b, values| {
+            b.iter(|| {
+                let mut measurements = WindDirectionMeasurements::new();
+                for &value in black_box(values) {
+                    measurements.add_measurement(value);
+                }
+                measurements
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", size), &values, |b, values| {
+            b.iter(|| {
+                let mut measurements = WindDirectionMeasurements::new();
+                measurements.add_measurements_batch(black_box(values));
+                measurements
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, add_measurements);
+criterion_main!(benches);
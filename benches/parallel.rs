@@ -0,0 +1,42 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use viater::WindDirectionMeasurements;
+
+fn from_values(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_values");
+    for size in [10_000, 100_000, 1_000_000] {
+        let values: Vec<f64> = (0..size).map(|i| (i % 360) as f64).collect();
+        group.bench_with_input(
+            BenchmarkId::new("sequential", size),
+            &values,
+            |b, values| b.iter(|| WindDirectionMeasurements::from_values(black_box(values))),
+        );
+        group.bench_with_input(BenchmarkId::new("parallel", size), &values, |b, values| {
+            b.iter(|| WindDirectionMeasurements::from_values_parallel(black_box(values)))
+        });
+    }
+    group.finish();
+}
+
+fn batch_from_slices(c: &mut Criterion) {
+    let stations: Vec<Vec<f64>> = (0..1000)
+        .map(|station| (0..1000).map(|i| ((i + station) % 360) as f64).collect())
+        .collect();
+    let slices: Vec<&[f64]> = stations.iter().map(Vec::as_slice).collect();
+    let mut group = c.benchmark_group("batch_from_slices");
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            black_box(&slices)
+                .iter()
+                .map(|angles| WindDirectionMeasurements::from_values(angles))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| WindDirectionMeasurements::batch_from_slices(black_box(&slices)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, from_values, batch_from_slices);
+criterion_main!(benches);
@@ -0,0 +1,285 @@
+use crate::DirectionError;
+use crate::special::rem_euclid;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Wraps any angle in degrees into [0, 360), e.g. 370 becomes 10 and -10 becomes 350.
+pub fn normalize_angle(degrees: f64) -> f64 {
+    let normalized = rem_euclid(degrees, 360.0);
+    // tiny negative inputs round up to exactly 360
+    if normalized == 360.0 { 0.0 } else { normalized }
+}
+
+/// Wraps any angle in degrees into `[min, max)`, which must span exactly 360°, e.g. -180 and 180
+/// for signed directions. `NAN` for any other width.
+pub fn wrap_to_range(degrees: f64, min: f64, max: f64) -> f64 {
+    if max - min != 360.0 {
+        return f64::NAN;
+    }
+    min + normalize_angle(degrees - min)
+}
+
+// Index of the sector containing `degrees` when the circle is split into `n_sectors` equal sectors,
+// the first one centered on north.
+#[cfg(feature = "alloc")]
+pub(crate) fn sector_index(degrees: f64, n_sectors: u32) -> usize {
+    let width = 360.0 / n_sectors as f64;
+    ((normalize_angle(degrees) + width / 2.0) / width) as usize % n_sectors as usize
+}
+
+/// Smallest separation between two directions in degrees, in [0, 180], e.g. 20 for 350° and 10°.
+pub fn angular_distance(a_degrees: f64, b_degrees: f64) -> f64 {
+    angular_difference_signed(a_degrees, b_degrees).abs()
+}
+
+/// Signed difference `a - b` in degrees taking the shorter way around the circle, in (-180, 180].
+/// Positive values mean `a` is clockwise from `b`.
+pub fn angular_difference_signed(a_degrees: f64, b_degrees: f64) -> f64 {
+    let difference = rem_euclid(a_degrees - b_degrees, 360.0);
+    if difference > 180.0 {
+        difference - 360.0
+    } else {
+        difference
+    }
+}
+
+/// Interpolates from `a_degrees` (`t = 0`) to `b_degrees` (`t = 1`) along the shorter arc, at a
+/// constant angular rate. `t` is clamped to [0, 1] and the result is in [0, 360). Directions
+/// exactly opposite each other are interpolated clockwise.
+pub fn interpolate_direction(a_degrees: f64, b_degrees: f64, t: f64) -> f64 {
+    let t = t.clamp(0.0, 1.0);
+    normalize_angle(a_degrees + t * angular_difference_signed(b_degrees, a_degrees))
+}
+
+/// Converts a meteorological direction (where the wind comes from) to the direction the wind
+/// blows toward, e.g. a north wind (0°) blows toward 180°. The result is in [0, 360).
+pub fn meteorological_to_mathematical(degrees: f64) -> f64 {
+    normalize_angle(degrees + 180.0)
+}
+
+/// Inverse of [`meteorological_to_mathematical`], from the direction the wind blows toward to
+/// where it comes from. The result is in [0, 360).
+pub fn mathematical_to_meteorological(degrees: f64) -> f64 {
+    normalize_angle(degrees - 180.0)
+}
+
+/// Converts a navigation bearing (clockwise from north) to a mathematical angle
+/// (counterclockwise from east), in [0, 360). Both describe the same direction, unlike
+/// [`meteorological_to_mathematical`] which turns where the wind comes from into where it goes.
+///
+/// | Direction | Bearing | Mathematical angle |
+/// |-----------|---------|--------------------|
+/// | N         | 0       | 90                 |
+/// | E         | 90      | 0                  |
+/// | S         | 180     | 270                |
+/// | W         | 270     | 180                |
+pub fn geographic_to_math(bearing_deg: f64) -> f64 {
+    normalize_angle(90.0 - bearing_deg)
+}
+
+/// Inverse of [`geographic_to_math`], from a mathematical angle (counterclockwise from east) to
+/// a navigation bearing (clockwise from north), in [0, 360).
+pub fn math_to_geographic(math_deg: f64) -> f64 {
+    normalize_angle(90.0 - math_deg)
+}
+
+/// Converts degrees, minutes and seconds to decimal degrees, e.g. 10° 30' 36" becomes 10.51. The
+/// sign of `degrees` applies to the whole angle. Fails when `minutes` or `seconds` is not in
+/// [0, 60).
+pub fn from_degrees_minutes_seconds(
+    degrees: i32,
+    minutes: u32,
+    seconds: f64,
+) -> Result<f64, DirectionError> {
+    DegreesMinutesSeconds {
+        negative: degrees < 0,
+        degrees: degrees.unsigned_abs(),
+        minutes,
+        seconds,
+    }
+    .to_decimal()
+}
+
+/// An angle split into unsigned degrees, minutes and seconds with a separate sign, so angles in
+/// (-1, 0) such as -0° 30' keep it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DegreesMinutesSeconds {
+    pub negative: bool,
+    pub degrees: u32,
+    pub minutes: u32,
+    pub seconds: f64,
+}
+
+impl DegreesMinutesSeconds {
+    /// Decimal degrees, fails when `minutes` or `seconds` is not in [0, 60).
+    pub fn to_decimal(&self) -> Result<f64, DirectionError> {
+        if self.minutes >= 60 || !(0.0..60.0).contains(&self.seconds) {
+            return Err(DirectionError::InvalidDegreesMinutesSeconds);
+        }
+        let magnitude = self.degrees as f64 + self.minutes as f64 / 60.0 + self.seconds / 3600.0;
+        Ok(if self.negative { -magnitude } else { magnitude })
+    }
+}
+
+/// Splits decimal degrees into degrees, minutes and seconds, the inverse of
+/// [`DegreesMinutesSeconds::to_decimal`].
+pub fn to_degrees_minutes_seconds(decimal: f64) -> DegreesMinutesSeconds {
+    let magnitude = decimal.abs();
+    let degrees = magnitude.floor();
+    let total_minutes = (magnitude - degrees) * 60.0;
+    let minutes = total_minutes.floor();
+    DegreesMinutesSeconds {
+        negative: decimal < 0.0,
+        degrees: degrees as u32,
+        minutes: minutes as u32,
+        seconds: (total_minutes - minutes) * 60.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_angle(0.0), 0.0);
+        assert_eq!(normalize_angle(45.5), 45.5);
+        assert_eq!(normalize_angle(-10.0), 350.0);
+        assert_eq!(normalize_angle(-370.0), 350.0);
+        assert_eq!(normalize_angle(370.0), 10.0);
+        assert_eq!(normalize_angle(360.0), 0.0);
+        assert_eq!(normalize_angle(720.0), 0.0);
+        assert_eq!(normalize_angle(-720.0), 0.0);
+        assert_eq!(normalize_angle(-1e-20), 0.0);
+    }
+
+    #[test]
+    fn wrap_to_any_range() {
+        assert_eq!(wrap_to_range(-10.0, 0.0, 360.0), 350.0);
+        assert_eq!(wrap_to_range(200.0, -180.0, 180.0), -160.0);
+        assert_eq!(wrap_to_range(180.0, -180.0, 180.0), -180.0);
+        assert_eq!(wrap_to_range(-180.0, -180.0, 180.0), -180.0);
+        assert_eq!(wrap_to_range(45.0, -90.0, 270.0), 45.0);
+        assert_eq!(wrap_to_range(-100.0, -90.0, 270.0), 260.0);
+        assert!(wrap_to_range(10.0, -90.0, 90.0).is_nan());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sectors_are_centered_on_north() {
+        assert_eq!(sector_index(0.0, 8), 0);
+        assert_eq!(sector_index(22.4, 8), 0);
+        assert_eq!(sector_index(22.5, 8), 1);
+        assert_eq!(sector_index(340.0, 8), 0);
+        assert_eq!(sector_index(337.4, 8), 7);
+        assert_eq!(sector_index(180.0, 4), 2);
+    }
+
+    #[test]
+    fn distance_wraps_around_north() {
+        assert_eq!(angular_distance(350.0, 10.0), 20.0);
+        assert_eq!(angular_distance(10.0, 350.0), 20.0);
+        assert_eq!(angular_distance(0.0, 180.0), 180.0);
+        assert_eq!(angular_distance(90.0, 90.0), 0.0);
+        assert_eq!(angular_distance(-90.0, 630.0), 0.0);
+    }
+
+    #[test]
+    fn interpolation_takes_shorter_arc() {
+        assert_eq!(interpolate_direction(350.0, 10.0, 0.5), 0.0);
+        assert_eq!(interpolate_direction(10.0, 350.0, 0.5), 0.0);
+        assert_eq!(interpolate_direction(10.0, 350.0, 0.0), 10.0);
+        assert_eq!(interpolate_direction(10.0, 350.0, 1.0), 350.0);
+        assert_eq!(interpolate_direction(10.0, 350.0, 0.25), 5.0);
+        assert_eq!(interpolate_direction(0.0, 180.0, 0.5), 90.0);
+        assert_eq!(interpolate_direction(10.0, 350.0, 2.0), 350.0);
+        assert_eq!(interpolate_direction(10.0, 350.0, -1.0), 10.0);
+    }
+
+    #[test]
+    fn geographic_conversion() {
+        for (bearing, math) in [(0.0, 90.0), (90.0, 0.0), (180.0, 270.0), (270.0, 180.0)] {
+            assert_eq!(geographic_to_math(bearing), math);
+            assert_eq!(math_to_geographic(math), bearing);
+        }
+        assert_eq!(geographic_to_math(360.0), 90.0);
+        assert_eq!(geographic_to_math(45.0), 45.0);
+        assert_eq!(math_to_geographic(-90.0), 180.0);
+        assert_eq!(math_to_geographic(geographic_to_math(123.5)), 123.5);
+    }
+
+    #[test]
+    fn convention_conversion() {
+        assert_eq!(meteorological_to_mathematical(0.0), 180.0);
+        assert_eq!(meteorological_to_mathematical(270.0), 90.0);
+        assert_eq!(meteorological_to_mathematical(-90.0), 90.0);
+        assert_eq!(mathematical_to_meteorological(180.0), 0.0);
+        assert_eq!(mathematical_to_meteorological(10.0), 190.0);
+        for degrees in [0.0, 12.5, 90.0, 179.0, 180.0, 181.0, 359.5] {
+            assert_eq!(
+                mathematical_to_meteorological(meteorological_to_mathematical(degrees)),
+                degrees
+            );
+            assert_eq!(
+                meteorological_to_mathematical(mathematical_to_meteorological(degrees)),
+                degrees
+            );
+        }
+    }
+
+    #[test]
+    fn degrees_minutes_seconds() {
+        assert_eq!(from_degrees_minutes_seconds(10, 30, 36.0), Ok(10.51));
+        assert_eq!(from_degrees_minutes_seconds(-10, 30, 0.0), Ok(-10.5));
+        assert_eq!(
+            from_degrees_minutes_seconds(0, 59, 59.999).map(|d| d < 1.0),
+            Ok(true)
+        );
+        assert_eq!(
+            from_degrees_minutes_seconds(10, 60, 0.0),
+            Err(DirectionError::InvalidDegreesMinutesSeconds)
+        );
+        assert_eq!(
+            from_degrees_minutes_seconds(10, 0, 60.0),
+            Err(DirectionError::InvalidDegreesMinutesSeconds)
+        );
+        assert_eq!(
+            from_degrees_minutes_seconds(10, 0, -1.0),
+            Err(DirectionError::InvalidDegreesMinutesSeconds)
+        );
+        assert_eq!(
+            to_degrees_minutes_seconds(-10.5),
+            DegreesMinutesSeconds {
+                negative: true,
+                degrees: 10,
+                minutes: 30,
+                seconds: 0.0
+            }
+        );
+        for decimal in [0.0, 10.51, 123.456, 359.999, -45.25, -0.5, -0.0001] {
+            let split = to_degrees_minutes_seconds(decimal);
+            assert!(split.minutes < 60 && (0.0..60.0).contains(&split.seconds));
+            assert!((split.to_decimal().unwrap() - decimal).abs() < 1e-9);
+        }
+        assert!(to_degrees_minutes_seconds(-0.5).to_decimal().unwrap() < 0.0);
+        assert_eq!(
+            DegreesMinutesSeconds {
+                negative: false,
+                degrees: 1,
+                minutes: 60,
+                seconds: 0.0
+            }
+            .to_decimal(),
+            Err(DirectionError::InvalidDegreesMinutesSeconds)
+        );
+    }
+
+    #[test]
+    fn signed_difference() {
+        assert_eq!(angular_difference_signed(10.0, 350.0), 20.0);
+        assert_eq!(angular_difference_signed(350.0, 10.0), -20.0);
+        assert_eq!(angular_difference_signed(180.0, 0.0), 180.0);
+        assert_eq!(angular_difference_signed(0.0, 180.0), 180.0);
+        assert_eq!(angular_difference_signed(270.0, 0.0), -90.0);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::WindDirectionMeasurements;
+use alloc::vec::Vec;
+
+/// [`WindDirectionMeasurements::average_direction`] of each accumulator, in the same order. With
+/// the `rayon` feature the accumulators are processed in parallel.
+pub fn batch_mean_direction(windows: &[WindDirectionMeasurements]) -> Vec<f64> {
+    map_windows(windows, WindDirectionMeasurements::average_direction)
+}
+
+/// [`WindDirectionMeasurements::standard_deviation`] of each accumulator, in the same order. With
+/// the `rayon` feature the accumulators are processed in parallel.
+pub fn batch_standard_deviation(windows: &[WindDirectionMeasurements]) -> Vec<f64> {
+    map_windows(windows, WindDirectionMeasurements::standard_deviation)
+}
+
+fn map_windows(
+    windows: &[WindDirectionMeasurements],
+    statistic: impl Fn(&WindDirectionMeasurements) -> f64 + Sync + Send,
+) -> Vec<f64> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        windows.par_iter().map(statistic).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        windows.iter().map(statistic).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_per_element() {
+        let windows: Vec<WindDirectionMeasurements> = (0..24)
+            .map(|hour| {
+                WindDirectionMeasurements::from_values(&[
+                    hour as f64 * 15.0,
+                    hour as f64 * 15.0 + 20.0,
+                ])
+            })
+            .chain([WindDirectionMeasurements::new()])
+            .collect();
+        let means = batch_mean_direction(&windows);
+        let deviations = batch_standard_deviation(&windows);
+        assert_eq!(means.len(), windows.len());
+        assert_eq!(deviations.len(), windows.len());
+        for (index, window) in windows.iter().take(24).enumerate() {
+            assert_eq!(means[index], window.average_direction());
+            assert_eq!(deviations[index], window.standard_deviation());
+        }
+        assert!(means[24].is_nan() && deviations[24].is_nan());
+        assert!(batch_mean_direction(&[]).is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::WindDirectionMeasurements;
+use crate::angles::sector_index;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// One accumulator per direction sector, e.g. 16 compass sectors. Sectors are the same as in
+/// [`crate::DirectionMeasurementsWithHistory::sector_frequency`], the first one centered on
+/// north and the order clockwise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectionBins {
+    bins: Vec<WindDirectionMeasurements>,
+    n_sectors: u32,
+}
+
+impl DirectionBins {
+    pub fn new(n_sectors: u32) -> Self {
+        DirectionBins {
+            bins: alloc::vec![WindDirectionMeasurements::new(); n_sectors as usize],
+            n_sectors,
+        }
+    }
+
+    pub fn n_sectors(&self) -> u32 {
+        self.n_sectors
+    }
+
+    /// Adds the angle to the accumulator of its sector, ignored when there are no sectors.
+    pub fn add_to_bin(&mut self, angle_degrees: f64) {
+        if let Some(index) = self.bin_index(angle_degrees) {
+            self.bins[index].add_measurement(angle_degrees);
+        }
+    }
+
+    // Sector of the angle, `None` when there are no sectors.
+    pub(crate) fn bin_index(&self, angle_degrees: f64) -> Option<usize> {
+        (self.n_sectors > 0).then(|| sector_index(angle_degrees, self.n_sectors))
+    }
+
+    pub fn bin_statistics(&self, index: usize) -> Option<&WindDirectionMeasurements> {
+        self.bins.get(index)
+    }
+
+    /// Index of the sector with the most measurements, the lowest index wins ties.
+    pub fn dominant_bin(&self) -> usize {
+        let mut dominant = 0;
+        for (index, bin) in self.bins.iter().enumerate() {
+            if bin.count() > self.bins[dominant].count() {
+                dominant = index;
+            }
+        }
+        dominant
+    }
+}
+
+/// Per-sector direction and wind speed statistics, sectors as in [`DirectionBins`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectionSpeedAccumulator {
+    bins: DirectionBins,
+    // (count, mean_speed, m2) for each sector, updated with Welford's method so the variance does
+    // not cancel catastrophically for large, nearly constant speeds
+    speeds: Vec<(u64, f64, f64)>,
+}
+
+impl DirectionSpeedAccumulator {
+    pub fn new(n_sectors: u32) -> Self {
+        DirectionSpeedAccumulator {
+            bins: DirectionBins::new(n_sectors),
+            speeds: alloc::vec![(0, 0.0, 0.0); n_sectors as usize],
+        }
+    }
+
+    /// Adds a direction in degrees with the wind speed measured at the same time, ignored when
+    /// there are no sectors.
+    pub fn add(&mut self, angle_degrees: f64, speed: f64) {
+        if let Some(index) = self.bins.bin_index(angle_degrees) {
+            self.bins.add_to_bin(angle_degrees);
+            let (count, mean, m2) = &mut self.speeds[index];
+            *count += 1;
+            let delta = speed - *mean;
+            *mean += delta / *count as f64;
+            *m2 += delta * (speed - *mean);
+        }
+    }
+
+    /// Direction statistics of each sector.
+    pub fn bins(&self) -> &DirectionBins {
+        &self.bins
+    }
+
+    /// Mean wind speed in sector `i`, `NAN` when it is empty or out of range.
+    pub fn sector_mean_speed(&self, i: usize) -> f64 {
+        match self.speeds.get(i) {
+            Some(&(count, mean, _)) if count > 0 => mean,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Population standard deviation of the wind speed in sector `i`, `NAN` when it is empty or
+    /// out of range.
+    pub fn sector_speed_std(&self, i: usize) -> f64 {
+        match self.speeds.get(i) {
+            Some(&(count, _, m2)) if count > 0 => (m2 / count as f64).sqrt(),
+            _ => f64::NAN,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_input() {
+        let mut bins = DirectionBins::new(16);
+        for i in 0..160 {
+            bins.add_to_bin(i as f64 * 2.25);
+        }
+        for index in 0..16 {
+            assert_eq!(bins.bin_statistics(index).unwrap().count(), 10);
+        }
+        assert!(bins.bin_statistics(16).is_none());
+        assert_eq!(bins.dominant_bin(), 0);
+    }
+
+    #[test]
+    fn routes_to_sector() {
+        let mut bins = DirectionBins::new(4);
+        for angle in [350.0, 10.0, 80.0, 100.0, 95.0] {
+            bins.add_to_bin(angle);
+        }
+        assert_eq!(bins.dominant_bin(), 1);
+        let east = bins.bin_statistics(1).unwrap();
+        assert_eq!(east.count(), 3);
+        assert!((east.average_direction() - 91.67).abs() < 0.1);
+        let north = bins.bin_statistics(0).unwrap();
+        assert!(
+            north
+                .average_direction()
+                .min(360.0 - north.average_direction())
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn speed_per_sector() {
+        let mut accumulator = DirectionSpeedAccumulator::new(4);
+        for (angle, speed) in [(350.0, 2.0), (10.0, 4.0), (5.0, 6.0), (180.0, 3.0)] {
+            accumulator.add(angle, speed);
+        }
+        assert!((accumulator.sector_mean_speed(0) - 4.0).abs() < 1e-12);
+        assert!((accumulator.sector_speed_std(0) - (8f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert!((accumulator.sector_mean_speed(2) - 3.0).abs() < 1e-12);
+        assert_eq!(accumulator.sector_speed_std(2), 0.0);
+        assert!(accumulator.sector_mean_speed(1).is_nan());
+        assert!(accumulator.sector_speed_std(1).is_nan());
+        assert!(accumulator.sector_mean_speed(4).is_nan());
+        assert_eq!(accumulator.bins().bin_statistics(0).unwrap().count(), 3);
+        assert_eq!(accumulator.bins().dominant_bin(), 0);
+
+        let mut empty = DirectionSpeedAccumulator::new(0);
+        empty.add(10.0, 1.0);
+        assert!(empty.sector_mean_speed(0).is_nan());
+    }
+
+    #[test]
+    fn speed_std_with_large_offset() {
+        let mut accumulator = DirectionSpeedAccumulator::new(4);
+        for speed in [2.0, 4.0, 6.0] {
+            accumulator.add(10.0, 1e9 + speed);
+        }
+        assert!((accumulator.sector_mean_speed(0) - (1e9 + 4.0)).abs() < 1e-6);
+        assert!((accumulator.sector_speed_std(0) - (8f64 / 3.0).sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn no_sectors() {
+        let mut bins = DirectionBins::new(0);
+        bins.add_to_bin(10.0);
+        assert!(bins.bin_statistics(0).is_none());
+        assert_eq!(bins.dominant_bin(), 0);
+    }
+}
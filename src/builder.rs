@@ -0,0 +1,156 @@
+use crate::strict::validate_angle;
+use crate::{AllowOutOfRange, DirectionError, WindDirectionMeasurements, YamartinoConfig};
+use core::f64::consts::TAU;
+
+/// Collects the optional behaviors of an accumulator before construction, see
+/// [`ConfiguredDirectionMeasurements`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DirectionMeasurementsBuilder {
+    yamartino: YamartinoConfig,
+    strict_validation: bool,
+    radian_input: bool,
+}
+
+impl DirectionMeasurementsBuilder {
+    pub fn new() -> Self {
+        DirectionMeasurementsBuilder::default()
+    }
+
+    /// Correction constant of the Yamartino standard deviation, see [`YamartinoConfig::b`].
+    pub fn with_yamartino_b(mut self, b: f64) -> Self {
+        self.yamartino.b = b;
+        self
+    }
+
+    /// Reject NaN, infinite and out-of-range angles instead of adding them.
+    pub fn with_strict_validation(mut self, strict_validation: bool) -> Self {
+        self.strict_validation = strict_validation;
+        self
+    }
+
+    /// Interpret added angles as radians instead of degrees, statistics are still in degrees.
+    pub fn with_radian_input(mut self, radian_input: bool) -> Self {
+        self.radian_input = radian_input;
+        self
+    }
+
+    /// Creates the configured accumulator, failing with [`DirectionError::InvalidConfiguration`]
+    /// unless the Yamartino `b` is positive and finite.
+    pub fn build(self) -> Result<ConfiguredDirectionMeasurements, DirectionError> {
+        if !(self.yamartino.b > 0.0 && self.yamartino.b.is_finite()) {
+            return Err(DirectionError::InvalidConfiguration);
+        }
+        Ok(ConfiguredDirectionMeasurements {
+            inner: WindDirectionMeasurements::new(),
+            config: self,
+        })
+    }
+}
+
+/// Accumulator created by [`DirectionMeasurementsBuilder`] that applies its configuration when
+/// measurements are added and statistics computed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConfiguredDirectionMeasurements {
+    inner: WindDirectionMeasurements,
+    config: DirectionMeasurementsBuilder,
+}
+
+impl ConfiguredDirectionMeasurements {
+    /// Adds an angle in the configured unit. With strict validation NaN, infinite and angles
+    /// outside [0, 360] degrees ([0, 2π] radians) are rejected and leave the accumulator
+    /// unchanged, otherwise this always succeeds.
+    pub fn add_measurement(&mut self, angle: f64) -> Result<(), DirectionError> {
+        if self.config.strict_validation {
+            let full_circle = if self.config.radian_input { TAU } else { 360.0 };
+            validate_angle(angle, full_circle, AllowOutOfRange(false))?;
+        }
+        if self.config.radian_input {
+            self.inner.add_measurement_radians(angle);
+        } else {
+            self.inner.add_measurement(angle);
+        }
+        Ok(())
+    }
+
+    /// The accumulated measurements, for statistics not forwarded by this type.
+    pub fn measurements(&self) -> &WindDirectionMeasurements {
+        &self.inner
+    }
+
+    pub fn average_direction(&self) -> f64 {
+        self.inner.average_direction()
+    }
+
+    /// Standard deviation in degrees with the configured Yamartino constant.
+    pub fn standard_deviation(&self) -> f64 {
+        self.inner
+            .standard_deviation_with_config(&self.config.yamartino)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: [f64; 8] = [350.0, 10.0, 20.0, 200.0, 275.0, 5.0, 45.0, 310.0];
+
+    #[test]
+    fn default_matches_direct_construction() {
+        let mut configured = DirectionMeasurementsBuilder::new().build().unwrap();
+        for angle in VALUES {
+            configured.add_measurement(angle).unwrap();
+        }
+        let direct = WindDirectionMeasurements::from_values(&VALUES);
+        assert_eq!(*configured.measurements(), direct);
+        assert_eq!(configured.average_direction(), direct.average_direction());
+        assert_eq!(configured.standard_deviation(), direct.standard_deviation());
+    }
+
+    #[test]
+    fn custom_yamartino_b() {
+        let mut configured = DirectionMeasurementsBuilder::new()
+            .with_yamartino_b(0.5)
+            .build()
+            .unwrap();
+        for angle in VALUES {
+            configured.add_measurement(angle).unwrap();
+        }
+        let direct = WindDirectionMeasurements::from_values(&VALUES);
+        assert!(configured.standard_deviation() > direct.standard_deviation());
+    }
+
+    #[test]
+    fn rejects_invalid_b() {
+        for b in [0.0, -1.0, f64::NAN] {
+            assert_eq!(
+                DirectionMeasurementsBuilder::new()
+                    .with_yamartino_b(b)
+                    .build(),
+                Err(DirectionError::InvalidConfiguration)
+            );
+        }
+    }
+
+    #[test]
+    fn strict_radian_input() {
+        let mut configured = DirectionMeasurementsBuilder::new()
+            .with_strict_validation(true)
+            .with_radian_input(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            configured.add_measurement(core::f64::consts::FRAC_PI_2),
+            Ok(())
+        );
+        assert_eq!(
+            configured.add_measurement(10.0),
+            Err(DirectionError::AngleOutOfRange)
+        );
+        assert_eq!(
+            configured.add_measurement(f64::NAN),
+            Err(DirectionError::NonFiniteAngle)
+        );
+        assert!((configured.average_direction() - 90.0).abs() < 1e-9);
+        assert_eq!(configured.measurements().count(), 1);
+    }
+}
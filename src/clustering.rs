@@ -0,0 +1,95 @@
+use crate::{WindDirectionMeasurements, angular_distance, normalize_angle};
+use alloc::vec::Vec;
+
+/// Splits directions into `k` clusters with k-means, using the circular mean for the centroid
+/// update, and returns the cluster centers in degrees sorted in [0, 360).
+///
+/// Initial centers are chosen deterministically by farthest-point selection starting from the
+/// first angle, iteration stops after `max_iter` rounds or when no assignment changes. A cluster
+/// that ends up empty or without a defined mean keeps its previous center. Returns an empty
+/// `Vec` when `angles` is empty or `k` is 0.
+pub fn k_means_circular(angles: &[f64], k: usize, max_iter: u32) -> Vec<f64> {
+    if angles.is_empty() || k == 0 {
+        return Vec::new();
+    }
+    let nearest = |centers: &[f64], angle: f64| {
+        let mut nearest = 0;
+        for (index, &center) in centers.iter().enumerate() {
+            if angular_distance(angle, center) < angular_distance(angle, centers[nearest]) {
+                nearest = index;
+            }
+        }
+        nearest
+    };
+
+    let mut centers = Vec::with_capacity(k);
+    centers.push(normalize_angle(angles[0]));
+    while centers.len() < k {
+        let farthest = angles
+            .iter()
+            .copied()
+            .max_by(|&a, &b| {
+                let distance =
+                    |angle: f64| angular_distance(angle, centers[nearest(&centers, angle)]);
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap_or(angles[0]);
+        centers.push(normalize_angle(farthest));
+    }
+
+    let mut assignments = alloc::vec![usize::MAX; angles.len()];
+    for _ in 0..max_iter {
+        let mut changed = false;
+        let mut clusters = alloc::vec![WindDirectionMeasurements::new(); k];
+        for (assignment, &angle) in assignments.iter_mut().zip(angles) {
+            let cluster = nearest(&centers, angle);
+            changed |= *assignment != cluster;
+            *assignment = cluster;
+            clusters[cluster].add_measurement(angle);
+        }
+        if !changed {
+            break;
+        }
+        for (center, cluster) in centers.iter_mut().zip(&clusters) {
+            let mean = cluster.average_direction();
+            if !mean.is_nan() {
+                *center = mean;
+            }
+        }
+    }
+    centers.sort_by(f64::total_cmp);
+    centers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separates_opposite_clusters() {
+        let angles = [355.0, 2.0, 178.0, 5.0, 182.0, 358.0, 185.0, 1.0, 175.0];
+        let centers = k_means_circular(&angles, 2, 100);
+        assert_eq!(centers.len(), 2);
+        assert!(angular_distance(centers[0], 0.0) < 5.0);
+        assert!(angular_distance(centers[1], 180.0) < 5.0);
+    }
+
+    #[test]
+    fn single_cluster_is_circular_mean() {
+        let angles = [350.0, 10.0, 20.0, 340.0];
+        let centers = k_means_circular(&angles, 1, 10);
+        let mean = WindDirectionMeasurements::from_values(&angles).average_direction();
+        assert_eq!(centers.len(), 1);
+        assert!((centers[0] - mean).abs() < 1e-9);
+    }
+
+    #[test]
+    fn degenerate_input() {
+        assert!(k_means_circular(&[], 3, 10).is_empty());
+        assert!(k_means_circular(&[10.0], 0, 10).is_empty());
+        assert_eq!(
+            k_means_circular(&[10.0, 20.0], 2, 0),
+            alloc::vec![10.0, 20.0]
+        );
+    }
+}
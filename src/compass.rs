@@ -0,0 +1,134 @@
+use crate::{ParseError, WindDirectionMeasurements, normalize_angle};
+
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+const SECTOR_WIDTH: f64 = 360.0 / COMPASS_POINTS.len() as f64;
+
+/// Direction in degrees of one of the 16 compass points, e.g. 22.5 for `"NNE"`. Matching ignores
+/// case and surrounding whitespace.
+pub fn from_compass_notation(notation: &str) -> Result<f64, ParseError> {
+    let notation = notation.trim();
+    COMPASS_POINTS
+        .iter()
+        .position(|point| point.eq_ignore_ascii_case(notation))
+        .map(|index| index as f64 * SECTOR_WIDTH)
+        .ok_or(ParseError::UnknownCompassPoint)
+}
+
+/// Nearest of the 16 compass points for a direction in degrees, NaN maps to `"N"`.
+pub fn to_compass_point(degrees: f64) -> &'static str {
+    let index = (normalize_angle(degrees) / SECTOR_WIDTH + 0.5) as usize % COMPASS_POINTS.len();
+    COMPASS_POINTS[index]
+}
+
+impl WindDirectionMeasurements {
+    /// Accumulates bearings given either as three-digit degrees from `"000"` to `"360"` or as
+    /// compass points (`"SSW"`), detected per token. Fails with the index of the first token that
+    /// is neither.
+    pub fn from_bearing_string_slice(bearings: &[&str]) -> Result<Self, ParseError> {
+        let mut measurements = WindDirectionMeasurements::new();
+        for (index, bearing) in bearings.iter().enumerate() {
+            let bearing = bearing.trim();
+            let angle_degrees = if bearing.len() == 3 && bearing.bytes().all(|b| b.is_ascii_digit())
+            {
+                match bearing.parse::<u16>() {
+                    Ok(degrees) if degrees <= 360 => f64::from(degrees),
+                    _ => return Err(ParseError::InvalidBearing { index }),
+                }
+            } else {
+                from_compass_notation(bearing).map_err(|_| ParseError::InvalidBearing { index })?
+            };
+            measurements.add_measurement(angle_degrees);
+        }
+        Ok(measurements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_bearings() {
+        let measurements =
+            WindDirectionMeasurements::from_bearing_string_slice(&["090", "180", "N", " ssw "])
+                .unwrap();
+        let expected = WindDirectionMeasurements::from_values(&[90.0, 180.0, 0.0, 202.5]);
+        assert_eq!(measurements, expected);
+        let westerly =
+            WindDirectionMeasurements::from_bearing_string_slice(&["W", "270", "WNW", "260"])
+                .unwrap();
+        let expected = WindDirectionMeasurements::from_values(&[270.0, 270.0, 292.5, 260.0]);
+        assert!((westerly.average_direction() - expected.average_direction()).abs() < 1e-9);
+        assert!((westerly.average_direction() - 273.1).abs() < 0.1);
+    }
+
+    #[test]
+    fn invalid_bearing() {
+        assert_eq!(
+            WindDirectionMeasurements::from_bearing_string_slice(&["090", "NORTH", "x"]),
+            Err(ParseError::InvalidBearing { index: 1 })
+        );
+        assert_eq!(
+            WindDirectionMeasurements::from_bearing_string_slice(&["", "N"]),
+            Err(ParseError::InvalidBearing { index: 0 })
+        );
+        assert_eq!(
+            WindDirectionMeasurements::from_bearing_string_slice(&["N", "NaN"]),
+            Err(ParseError::InvalidBearing { index: 1 })
+        );
+        for bearing in ["1e2", "-5", "45.5", "90", "361", "+90", "0090"] {
+            assert_eq!(
+                WindDirectionMeasurements::from_bearing_string_slice(&["000", bearing]),
+                Err(ParseError::InvalidBearing { index: 1 })
+            );
+        }
+        assert_eq!(
+            WindDirectionMeasurements::from_bearing_string_slice(&["000", "360"]).unwrap(),
+            WindDirectionMeasurements::from_values(&[0.0, 360.0])
+        );
+        assert!(
+            WindDirectionMeasurements::from_bearing_string_slice(&[])
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn all_compass_points() {
+        for (index, point) in COMPASS_POINTS.iter().enumerate() {
+            let degrees = index as f64 * 22.5;
+            assert_eq!(from_compass_notation(point), Ok(degrees));
+            assert_eq!(to_compass_point(degrees), *point);
+            assert_eq!(to_compass_point(degrees + 11.0), *point);
+            assert_eq!(to_compass_point(degrees - 11.0), *point);
+        }
+    }
+
+    #[test]
+    fn compass_notation_is_case_insensitive() {
+        assert_eq!(from_compass_notation(" ssw "), Ok(202.5));
+        assert_eq!(from_compass_notation("Nw"), Ok(315.0));
+    }
+
+    #[test]
+    fn unknown_compass_point() {
+        assert_eq!(
+            from_compass_notation("NNNE"),
+            Err(ParseError::UnknownCompassPoint)
+        );
+        assert_eq!(
+            from_compass_notation(""),
+            Err(ParseError::UnknownCompassPoint)
+        );
+    }
+
+    #[test]
+    fn nearest_compass_point_wraps_around_north() {
+        assert_eq!(to_compass_point(355.0), "N");
+        assert_eq!(to_compass_point(-5.0), "N");
+        assert_eq!(to_compass_point(348.0), "NNW");
+    }
+}
@@ -0,0 +1,66 @@
+use crate::WindDirectionMeasurements;
+use core::cmp::Ordering;
+use core::fmt;
+use num_traits::Float;
+
+/// Orders accumulators by [`WindDirectionMeasurements::mean_resultant_length`], so that
+/// `a < b` when `b` is more concentrated around its mean direction.
+///
+/// This is a separate wrapper because the ordering disagrees with the field-wise `PartialEq` of
+/// the accumulator: two different accumulators can be equally concentrated. Only `PartialOrd` is
+/// implemented since an empty accumulator has a `NAN` mean resultant length, which is not
+/// comparable to anything, so no total order (`Ord`) exists.
+#[derive(Clone, Copy)]
+pub struct ByConcentration<F = f64>(pub WindDirectionMeasurements<F>);
+
+impl<F: Float + fmt::Debug> fmt::Debug for ByConcentration<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ByConcentration").field(&self.0).finish()
+    }
+}
+
+impl<F: Float> PartialEq for ByConcentration<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.mean_resultant_length() == other.0.mean_resultant_length()
+    }
+}
+
+impl<F: Float> PartialOrd for ByConcentration<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0
+            .mean_resultant_length()
+            .partial_cmp(&other.0.mean_resultant_length())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_by_concentration() {
+        let tight = ByConcentration(WindDirectionMeasurements::from_values(&[10.0, 20.0]));
+        let loose = ByConcentration(WindDirectionMeasurements::from_values(&[10.0, 100.0]));
+        assert!(loose < tight);
+        assert!(tight > loose);
+        let mut stations = [tight, loose];
+        stations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(stations[0].0, loose.0);
+    }
+
+    #[test]
+    fn equal_concentration_with_different_fields() {
+        let north = ByConcentration(WindDirectionMeasurements::from_values(&[0.0, 0.0]));
+        let east = ByConcentration(WindDirectionMeasurements::from_values(&[90.0, 90.0]));
+        assert!(north == east);
+        assert!(north.0 != east.0);
+    }
+
+    #[test]
+    fn empty_is_not_comparable() {
+        let empty = ByConcentration(WindDirectionMeasurements::new());
+        let single = ByConcentration(WindDirectionMeasurements::from_values(&[0.0]));
+        assert_eq!(empty.partial_cmp(&single), None);
+        assert!(empty != empty);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::WindDirectionMeasurements;
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Lock-free accumulator that can be shared between threads, measurements are added through a
+/// shared reference.
+///
+/// Each running sum is an `f64` stored as bits in an [`AtomicU64`] and updated with a
+/// compare-and-swap loop. The comparison is on bit patterns only, so if a sum changes and then
+/// returns to the exact same bits between the load and the swap (ABA) the swap still succeeds;
+/// this is harmless here because the new value is always derived from the bits that were
+/// compared, but it means no ordering between individual measurements is implied.
+///
+/// [`Self::snapshot`] waits until no writer is in progress and retries if one started while the
+/// sums were read, so under constant contention it may spin for a while.
+#[derive(Debug, Default)]
+pub struct ConcurrentDirectionMeasurements {
+    count: AtomicU64,
+    sum_sin_rad: AtomicU64,
+    sum_cos_rad: AtomicU64,
+    sum_sin_2rad: AtomicU64,
+    sum_cos_2rad: AtomicU64,
+    writers_in_progress: AtomicU64,
+    completed_writes: AtomicU64,
+}
+
+fn atomic_add(target: &AtomicU64, value: f64) {
+    let _ = target.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |bits| {
+        Some((f64::from_bits(bits) + value).to_bits())
+    });
+}
+
+impl ConcurrentDirectionMeasurements {
+    pub fn new() -> Self {
+        ConcurrentDirectionMeasurements::default()
+    }
+
+    pub fn add_measurement(&self, angle_degrees: f64) {
+        let angle_rad = angle_degrees.to_radians();
+        let double_angle_rad = angle_rad + angle_rad;
+        self.writers_in_progress.fetch_add(1, Ordering::SeqCst);
+        self.count.fetch_add(1, Ordering::SeqCst);
+        atomic_add(&self.sum_sin_rad, angle_rad.sin());
+        atomic_add(&self.sum_cos_rad, angle_rad.cos());
+        atomic_add(&self.sum_sin_2rad, double_angle_rad.sin());
+        atomic_add(&self.sum_cos_2rad, double_angle_rad.cos());
+        self.completed_writes.fetch_add(1, Ordering::SeqCst);
+        self.writers_in_progress.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Consistent copy of the current state as a regular accumulator.
+    pub fn snapshot(&self) -> WindDirectionMeasurements {
+        loop {
+            let completed_before = self.completed_writes.load(Ordering::SeqCst);
+            if self.writers_in_progress.load(Ordering::SeqCst) != 0 {
+                core::hint::spin_loop();
+                continue;
+            }
+            let count = self.count.load(Ordering::SeqCst);
+            let load = |sum: &AtomicU64| f64::from_bits(sum.load(Ordering::SeqCst));
+            let snapshot = WindDirectionMeasurements {
+                count,
+                sum_weights: count as f64,
+                sum_sin_rad: load(&self.sum_sin_rad),
+                sum_cos_rad: load(&self.sum_cos_rad),
+                sum_sin_2rad: load(&self.sum_sin_2rad),
+                sum_cos_2rad: load(&self.sum_cos_2rad),
+            };
+            if self.writers_in_progress.load(Ordering::SeqCst) == 0
+                && self.completed_writes.load(Ordering::SeqCst) == completed_before
+            {
+                return snapshot;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_snapshot() {
+        let measurements = ConcurrentDirectionMeasurements::new();
+        assert!(measurements.snapshot().is_empty());
+        assert!(measurements.snapshot().average_direction().is_nan());
+    }
+
+    #[test]
+    fn concurrent_writers() {
+        let angles: Vec<f64> = (0..4000).map(|i| (i * 13 % 120) as f64 + 300.0).collect();
+        let measurements = ConcurrentDirectionMeasurements::new();
+        std::thread::scope(|scope| {
+            for chunk in angles.chunks(1000) {
+                let measurements = &measurements;
+                scope.spawn(move || {
+                    for &angle in chunk {
+                        measurements.add_measurement(angle);
+                    }
+                });
+            }
+            scope.spawn(|| {
+                let snapshot = measurements.snapshot();
+                assert!(snapshot.count() <= 4000);
+            });
+        });
+        let snapshot = measurements.snapshot();
+        let sequential = WindDirectionMeasurements::from_values(&angles);
+        assert_eq!(snapshot.count(), sequential.count());
+        assert!((snapshot.average_direction() - sequential.average_direction()).abs() < 1e-9);
+        assert!((snapshot.standard_deviation() - sequential.standard_deviation()).abs() < 1e-6);
+        assert!((snapshot.circular_skewness() - sequential.circular_skewness()).abs() < 1e-9);
+    }
+}
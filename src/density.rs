@@ -0,0 +1,88 @@
+use crate::special;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Kernel density estimate of the directions, evaluated at `n_points` equally spaced angles
+/// starting from 0°, as `(angle_degrees, density)` pairs with the density per degree.
+///
+/// Each angle contributes a von Mises kernel with concentration `κ = 1 / h²`, `h` the bandwidth
+/// in radians, which for small bandwidths is close to a wrapped Gaussian with standard deviation
+/// `bandwidth_deg`. Densities are `NAN` without angles or unless the bandwidth is positive.
+pub fn circular_kde(angles: &[f64], bandwidth_deg: f64, n_points: usize) -> Vec<(f64, f64)> {
+    let step = 360.0 / n_points as f64;
+    if angles.is_empty() || bandwidth_deg <= 0.0 || bandwidth_deg.is_nan() {
+        return (0..n_points).map(|i| (i as f64 * step, f64::NAN)).collect();
+    }
+    let kappa = bandwidth_deg.to_radians().powi(-2);
+    // kernel normalizing constant ln(2π I0(κ)), plus the conversion to a density per degree
+    let ln_norm =
+        core::f64::consts::TAU.ln() + special::ln_bessel_i0(kappa) - 1f64.to_radians().ln();
+    let angles_rad: Vec<f64> = angles.iter().map(|angle| angle.to_radians()).collect();
+    (0..n_points)
+        .map(|i| {
+            let point = i as f64 * step;
+            let point_rad = point.to_radians();
+            let sum: f64 = angles_rad
+                .iter()
+                .map(|&angle| (kappa * (point_rad - angle).cos() - ln_norm).exp())
+                .sum();
+            (point, sum / angles.len() as f64)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANGLES: [f64; 6] = [350.0, 355.0, 0.0, 5.0, 10.0, 2.0];
+
+    // trapezoidal rule around the full circle, including the segment back to 0°
+    fn integrate(density: &[(f64, f64)]) -> f64 {
+        let step = 360.0 / density.len() as f64;
+        (0..density.len())
+            .map(|i| (density[i].1 + density[(i + 1) % density.len()].1) / 2.0 * step)
+            .sum()
+    }
+
+    #[test]
+    fn integrates_to_one() {
+        for bandwidth in [2.0, 10.0, 90.0] {
+            let density = circular_kde(&ANGLES, bandwidth, 360);
+            assert_eq!(density.len(), 360);
+            assert!((integrate(&density) - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn peak_near_mean() {
+        let density = circular_kde(&ANGLES, 10.0, 360);
+        let (peak, _) =
+            density
+                .iter()
+                .copied()
+                .fold((f64::NAN, f64::NEG_INFINITY), |best, point| {
+                    if point.1 > best.1 { point } else { best }
+                });
+        assert!(peak <= 3.0 || peak >= 357.0);
+        assert!(density[180].1 < 1e-6);
+        assert_eq!(density[90].0, 90.0);
+    }
+
+    #[test]
+    fn undefined_density() {
+        assert!(
+            circular_kde(&[], 10.0, 4)
+                .iter()
+                .all(|(_, density)| density.is_nan())
+        );
+        assert!(
+            circular_kde(&ANGLES, 0.0, 4)
+                .iter()
+                .all(|(_, density)| density.is_nan())
+        );
+        assert_eq!(circular_kde(&[], 10.0, 4)[1].0, 90.0);
+        assert!(circular_kde(&ANGLES, 10.0, 0).is_empty());
+    }
+}
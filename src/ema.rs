@@ -0,0 +1,104 @@
+use crate::DirectionError;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Exponential moving average of the direction, each new measurement gets weight `alpha` and the
+/// weight of older ones decays by `1 - alpha`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EmaDirectionMeasurements {
+    alpha: f64,
+    ema_sin: f64,
+    ema_cos: f64,
+}
+
+impl EmaDirectionMeasurements {
+    /// Fails with [`DirectionError::InvalidConfiguration`] unless `alpha` is in (0, 1].
+    pub fn new(alpha: f64) -> Result<Self, DirectionError> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(DirectionError::InvalidConfiguration);
+        }
+        Ok(EmaDirectionMeasurements {
+            alpha,
+            ema_sin: 0.0,
+            ema_cos: 0.0,
+        })
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    pub fn add_measurement(&mut self, angle_degrees: f64) {
+        let angle_rad = angle_degrees.to_radians();
+        self.ema_sin = self.alpha * angle_rad.sin() + (1.0 - self.alpha) * self.ema_sin;
+        self.ema_cos = self.alpha * angle_rad.cos() + (1.0 - self.alpha) * self.ema_cos;
+    }
+
+    /// NaN before the first measurement (or when the averaged vector cancels out exactly).
+    pub fn average_direction(&self) -> f64 {
+        if self.ema_sin == 0.0 && self.ema_cos == 0.0 {
+            return f64::NAN;
+        }
+        let arctan_degrees = self.ema_sin.atan2(self.ema_cos).to_degrees();
+        // atan2 returns values in the range [-180, 180], so we need to normalize it to [0, 360]
+        if arctan_degrees < 0.0 {
+            arctan_degrees + 360.0
+        } else {
+            arctan_degrees
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::angular_distance;
+
+    #[test]
+    fn empty_is_nan() {
+        assert!(
+            EmaDirectionMeasurements::new(0.5)
+                .unwrap()
+                .average_direction()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn alpha_one_follows_last_measurement() {
+        let mut ema = EmaDirectionMeasurements::new(1.0).unwrap();
+        for angle in [10.0, 200.0, 355.0, 90.0] {
+            ema.add_measurement(angle);
+            assert!(angular_distance(ema.average_direction(), angle) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn small_alpha_changes_slowly() {
+        let mut ema = EmaDirectionMeasurements::new(0.001).unwrap();
+        for _ in 0..100 {
+            ema.add_measurement(0.0);
+        }
+        ema.add_measurement(90.0);
+        assert!(angular_distance(ema.average_direction(), 0.0) < 1.0);
+    }
+
+    #[test]
+    fn wraps_around_north() {
+        let mut ema = EmaDirectionMeasurements::new(0.5).unwrap();
+        ema.add_measurement(350.0);
+        ema.add_measurement(10.0);
+        let direction = ema.average_direction();
+        assert!(angular_distance(direction, 0.0) < 10.0);
+    }
+
+    #[test]
+    fn rejects_invalid_alpha() {
+        for alpha in [0.0, -0.5, 1.5, f64::NAN] {
+            assert_eq!(
+                EmaDirectionMeasurements::new(alpha),
+                Err(DirectionError::InvalidConfiguration)
+            );
+        }
+    }
+}
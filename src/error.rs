@@ -0,0 +1,109 @@
+use core::fmt;
+
+/// Error returned when text cannot be parsed into directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text is not one of the 16 compass points (N, NNE, NE, ...).
+    UnknownCompassPoint,
+    /// The token at `index` of a comma-separated list is not a valid number.
+    InvalidNumber { index: usize },
+    /// The text is not a JSON object as written by `to_json_object`.
+    InvalidJson,
+    /// The token at `index` is neither a number of degrees nor a compass point.
+    InvalidBearing { index: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownCompassPoint => write!(f, "unknown compass point"),
+            ParseError::InvalidNumber { index } => write!(f, "invalid number at position {index}"),
+            ParseError::InvalidJson => write!(f, "invalid JSON direction summary"),
+            ParseError::InvalidBearing { index } => {
+                write!(f, "invalid bearing at position {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Error returned when NMEA 0183 sentences cannot be read as wind directions, `index` is the
+/// position of the offending sentence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NmeaError {
+    /// The checksum after `*` is not two hex digits or does not match the sentence.
+    InvalidChecksum { index: usize },
+    /// The text does not start with `$` and a five character address such as `WIMWV`.
+    UnknownSentence { index: usize },
+    /// A valid sentence of a type other than `MWV`, e.g. a GPS fix.
+    NotWindSentence { index: usize },
+    /// The wind angle, reference or status field is missing or malformed.
+    InvalidField { index: usize },
+}
+
+impl fmt::Display for NmeaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NmeaError::InvalidChecksum { index } => {
+                write!(f, "invalid checksum in sentence {index}")
+            }
+            NmeaError::UnknownSentence { index } => {
+                write!(f, "sentence {index} is not an NMEA sentence")
+            }
+            NmeaError::NotWindSentence { index } => {
+                write!(f, "sentence {index} is not a wind speed and angle sentence")
+            }
+            NmeaError::InvalidField { index } => write!(f, "invalid field in sentence {index}"),
+        }
+    }
+}
+
+impl core::error::Error for NmeaError {}
+
+/// Error returned by the checked constructors and adders when an input would corrupt the sums.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirectionError {
+    /// The value at this index of the input is NaN or infinite.
+    InvalidAngle(usize),
+    /// A single measurement is NaN or infinite.
+    NonFiniteAngle,
+    /// A measurement is outside [0, 360] and out-of-range angles are not allowed.
+    AngleOutOfRange,
+    /// The snapshot was written with a different, unsupported layout version.
+    IncompatibleSnapshot { version: u32 },
+    /// Two inputs that must be paired element-wise have different lengths.
+    LengthMismatch { left: usize, right: usize },
+    /// The statistic is undefined without any input.
+    EmptyInput,
+    /// Minutes or seconds of a degrees-minutes-seconds angle are not in [0, 60).
+    InvalidDegreesMinutesSeconds,
+    /// A configuration option is invalid, e.g. a Yamartino `b` that is not positive or an
+    /// EMA `alpha` outside (0, 1].
+    InvalidConfiguration,
+}
+
+impl fmt::Display for DirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DirectionError::InvalidAngle(index) => {
+                write!(f, "non-finite angle at position {index}")
+            }
+            DirectionError::NonFiniteAngle => write!(f, "angle is not finite"),
+            DirectionError::AngleOutOfRange => write!(f, "angle is outside [0, 360]"),
+            DirectionError::IncompatibleSnapshot { version } => {
+                write!(f, "unsupported snapshot version {version}")
+            }
+            DirectionError::LengthMismatch { left, right } => {
+                write!(f, "input lengths differ ({left} and {right})")
+            }
+            DirectionError::EmptyInput => write!(f, "input is empty"),
+            DirectionError::InvalidDegreesMinutesSeconds => {
+                write!(f, "minutes and seconds must be in [0, 60)")
+            }
+            DirectionError::InvalidConfiguration => write!(f, "invalid configuration"),
+        }
+    }
+}
+
+impl core::error::Error for DirectionError {}
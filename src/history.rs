@@ -0,0 +1,427 @@
+use crate::angles::sector_index;
+use crate::{WindDirectionMeasurements, angular_distance, normalize_angle};
+use alloc::vec::Vec;
+
+/// Accumulator that additionally keeps the raw angles (in degrees) for statistics that cannot be
+/// computed from running sums alone.
+#[derive(Clone, Debug, Default)]
+pub struct DirectionMeasurementsWithHistory {
+    angles: Vec<f64>,
+    inner: WindDirectionMeasurements,
+}
+
+impl DirectionMeasurementsWithHistory {
+    pub fn new() -> Self {
+        DirectionMeasurementsWithHistory::default()
+    }
+
+    pub fn from_values(values: &[f64]) -> Self {
+        let mut measurements = DirectionMeasurementsWithHistory::new();
+        for &value in values {
+            measurements.add_measurement(value);
+        }
+        measurements
+    }
+
+    pub fn add_measurement(&mut self, angle_degrees: f64) {
+        self.angles.push(angle_degrees);
+        self.inner.add_measurement(angle_degrees);
+    }
+
+    /// Keeps only the angles for which `predicate` returns `true` and recomputes the running sums
+    /// from the remaining ones, e.g. to drop readings from a faulty period.
+    pub fn retain_measurements(&mut self, predicate: impl Fn(f64) -> bool) {
+        self.angles.retain(|&angle| predicate(angle));
+        self.inner = WindDirectionMeasurements::from_values(&self.angles);
+    }
+
+    /// The stored angles in insertion order, as they were added.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + '_ {
+        self.angles.iter().copied()
+    }
+
+    pub fn average_direction(&self) -> f64 {
+        self.inner.average_direction()
+    }
+
+    pub fn standard_deviation(&self) -> f64 {
+        self.inner.standard_deviation()
+    }
+
+    /// Circular median, the observed angle that minimizes the sum of angular distances to all
+    /// other observations. Runs in O(n²).
+    pub fn median_direction(&self) -> f64 {
+        let mut median = f64::NAN;
+        let mut smallest_sum = f64::INFINITY;
+        for &candidate in &self.angles {
+            let sum: f64 = self
+                .angles
+                .iter()
+                .map(|&angle| angular_distance(candidate, angle))
+                .sum();
+            if sum < smallest_sum {
+                smallest_sum = sum;
+                median = candidate;
+            }
+        }
+        median
+    }
+
+    /// Largest angular distance of an observation from the mean direction, `NAN` when the mean
+    /// is undefined.
+    pub fn max_deviation_from_mean(&self) -> f64 {
+        self.deviations_from_mean().fold(f64::NAN, f64::max)
+    }
+
+    /// Smallest angular distance of an observation from the mean direction, `NAN` when the mean
+    /// is undefined.
+    pub fn min_deviation_from_mean(&self) -> f64 {
+        self.deviations_from_mean().fold(f64::NAN, f64::min)
+    }
+
+    /// Circular mean absolute deviation, the average angular distance of the observations from
+    /// the mean direction. `NAN` when the mean is undefined.
+    pub fn circular_mean_absolute_deviation(&self) -> f64 {
+        if self.average_direction().is_nan() {
+            return f64::NAN;
+        }
+        self.deviations_from_mean().sum::<f64>() / self.angles.len() as f64
+    }
+
+    /// Stored angles ordered by their angular distance from the mean direction, closest first,
+    /// e.g. to inspect outliers at the end. Insertion order is kept for equal distances and when
+    /// the mean is undefined.
+    pub fn angles_sorted_by_circular_distance_from_mean(&self) -> Vec<f64> {
+        let mut angles = self.angles.clone();
+        let mean = self.average_direction();
+        if !mean.is_nan() {
+            angles
+                .sort_by(|&a, &b| angular_distance(a, mean).total_cmp(&angular_distance(b, mean)));
+        }
+        angles
+    }
+
+    fn deviations_from_mean(&self) -> impl Iterator<Item = f64> + '_ {
+        let mean = self.average_direction();
+        self.angles
+            .iter()
+            .filter(move |_| !mean.is_nan())
+            .map(move |&angle| angular_distance(angle, mean))
+    }
+
+    /// Fraction of observations in each of `n_sectors` equal sectors, e.g. for a wind rose. The
+    /// first sector is centered on 0° (so with 8 sectors it covers [337.5, 22.5)) and the order
+    /// is clockwise. Every fraction is `NAN` when there are no observations.
+    pub fn sector_frequency(&self, n_sectors: u32) -> Vec<f64> {
+        if n_sectors == 0 {
+            return Vec::new();
+        }
+        let mut counts = alloc::vec![0usize; n_sectors as usize];
+        for &angle in &self.angles {
+            counts[sector_index(angle, n_sectors)] += 1;
+        }
+        let total = self.angles.len() as f64;
+        counts
+            .into_iter()
+            .map(|count| {
+                if total == 0.0 {
+                    f64::NAN
+                } else {
+                    count as f64 / total
+                }
+            })
+            .collect()
+    }
+
+    /// [Mean resultant length](WindDirectionMeasurements::mean_resultant_length) of the
+    /// observations in each of `n_sectors` sectors, using the same sectors as
+    /// [`Self::sector_frequency`]. A populated sector with a low value holds diffuse rather than
+    /// clustered directions. Empty sectors give `NAN`.
+    pub fn sector_mean_resultant_length(&self, n_sectors: u32) -> Vec<f64> {
+        if n_sectors == 0 {
+            return Vec::new();
+        }
+        let mut sectors = alloc::vec![WindDirectionMeasurements::new(); n_sectors as usize];
+        for &angle in &self.angles {
+            sectors[sector_index(angle, n_sectors)].add_measurement(angle);
+        }
+        sectors
+            .iter()
+            .map(WindDirectionMeasurements::mean_resultant_length)
+            .collect()
+    }
+
+    /// Number of observations within `half_width` degrees of `center` (inclusive), e.g. a center
+    /// of 0 and half width of 45 covers [315, 45].
+    pub fn count_in_sector(&self, center: f64, half_width: f64) -> u64 {
+        self.angles
+            .iter()
+            .filter(|&&angle| angular_distance(angle, center) <= half_width)
+            .count() as u64
+    }
+
+    /// Index (in insertion order) of the first observation after which the running average
+    /// direction of the observations so far is within `tolerance_degrees` of `target_degrees`
+    /// (inclusive), or `None` if the average never enters that band. A single outlier in the band
+    /// does not count while the average is still elsewhere.
+    pub fn first_passage_direction(
+        &self,
+        target_degrees: f64,
+        tolerance_degrees: f64,
+    ) -> Option<usize> {
+        let mut running = WindDirectionMeasurements::new();
+        self.angles.iter().position(|&angle| {
+            running.add_measurement(angle);
+            angular_distance(running.average_direction(), target_degrees) <= tolerance_degrees
+        })
+    }
+
+    /// Bounds `(start_degrees, end_degrees)` of the most populated sector, using the same
+    /// sectors as [`Self::sector_frequency`]. Bounds are in [0, 360) so the sector centered on
+    /// north has `start > end`. Ties go to the sector with the lower start angle, `(NAN, NAN)` is
+    /// returned when there are no observations or sectors.
+    pub fn dominant_sector(&self, n_sectors: u32) -> (f64, f64) {
+        if self.angles.is_empty() || n_sectors == 0 {
+            return (f64::NAN, f64::NAN);
+        }
+        let width = 360.0 / n_sectors as f64;
+        let bounds = |index: usize| {
+            let center = index as f64 * width;
+            (
+                normalize_angle(center - width / 2.0),
+                normalize_angle(center + width / 2.0),
+            )
+        };
+        let frequencies = self.sector_frequency(n_sectors);
+        let mut dominant = 0;
+        for (index, &frequency) in frequencies.iter().enumerate() {
+            let is_tie_with_lower_start =
+                frequency == frequencies[dominant] && bounds(index).0 < bounds(dominant).0;
+            if frequency > frequencies[dominant] || is_tie_with_lower_start {
+                dominant = index;
+            }
+        }
+        bounds(dominant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_of_empty() {
+        assert!(
+            DirectionMeasurementsWithHistory::new()
+                .median_direction()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn median_of_symmetric_angles() {
+        let measurements = DirectionMeasurementsWithHistory::from_values(&[350.0, 0.0, 10.0]);
+        assert_eq!(measurements.median_direction(), 0.0);
+    }
+
+    #[test]
+    fn deviation_from_mean() {
+        let measurements =
+            DirectionMeasurementsWithHistory::from_values(&[330.0, 355.0, 5.0, 30.0]);
+        let max = measurements.max_deviation_from_mean();
+        let min = measurements.min_deviation_from_mean();
+        assert!(max > min);
+        assert!((max - 30.0).abs() < 1e-9);
+        assert!((min - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn deviation_from_mean_of_single_measurement() {
+        let measurements = DirectionMeasurementsWithHistory::from_values(&[123.0]);
+        assert!(measurements.max_deviation_from_mean().abs() < 1e-9);
+        assert!(measurements.min_deviation_from_mean().abs() < 1e-9);
+    }
+
+    #[test]
+    fn deviation_from_mean_of_empty() {
+        let measurements = DirectionMeasurementsWithHistory::new();
+        assert!(measurements.max_deviation_from_mean().is_nan());
+        assert!(measurements.min_deviation_from_mean().is_nan());
+    }
+
+    #[test]
+    fn circular_mean_absolute_deviation() {
+        let identical = DirectionMeasurementsWithHistory::from_values(&[200.0; 5]);
+        assert!(identical.circular_mean_absolute_deviation().abs() < 1e-9);
+        let spread = DirectionMeasurementsWithHistory::from_values(&[330.0, 355.0, 5.0, 30.0]);
+        assert!((spread.circular_mean_absolute_deviation() - 17.5).abs() < 1e-9);
+        let angles: Vec<f64> = (0..36).map(|i| i as f64 * 10.0).collect();
+        let uniform = DirectionMeasurementsWithHistory::from_values(&angles);
+        assert!((uniform.circular_mean_absolute_deviation() - 90.0).abs() < 1e-6);
+        assert!(
+            DirectionMeasurementsWithHistory::new()
+                .circular_mean_absolute_deviation()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn retain_measurements() {
+        let mut measurements = DirectionMeasurementsWithHistory::from_values(&[
+            350.0, 95.0, 180.0, 10.0, 265.0, 300.0, 120.0,
+        ]);
+        measurements.retain_measurements(|angle| (90.0..=270.0).contains(&angle));
+        let expected = DirectionMeasurementsWithHistory::from_values(&[95.0, 180.0, 265.0, 120.0]);
+        assert!(measurements.iter().eq(expected.iter()));
+        assert_eq!(
+            measurements.average_direction(),
+            expected.average_direction()
+        );
+        assert_eq!(
+            measurements.standard_deviation(),
+            expected.standard_deviation()
+        );
+        measurements.retain_measurements(|_| false);
+        assert!(measurements.average_direction().is_nan());
+    }
+
+    #[test]
+    fn iterates_in_insertion_order() {
+        let measurements = DirectionMeasurementsWithHistory::from_values(&[30.0, 370.0, 5.0]);
+        assert!(measurements.iter().eq([30.0, 370.0, 5.0]));
+        assert_eq!(DirectionMeasurementsWithHistory::new().iter().count(), 0);
+    }
+
+    #[test]
+    fn sorted_by_distance_from_mean() {
+        let measurements =
+            DirectionMeasurementsWithHistory::from_values(&[330.0, 30.0, 355.0, 5.0, 90.0]);
+        let sorted = measurements.angles_sorted_by_circular_distance_from_mean();
+        let mean = measurements.average_direction();
+        assert_eq!(sorted.len(), 5);
+        assert_eq!(sorted[4], 90.0);
+        assert!(
+            sorted
+                .windows(2)
+                .all(|pair| angular_distance(pair[0], mean) <= angular_distance(pair[1], mean))
+        );
+        assert!(
+            DirectionMeasurementsWithHistory::new()
+                .angles_sorted_by_circular_distance_from_mean()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn uniform_sector_frequency() {
+        let angles: Vec<f64> = (0..8).map(|i| i as f64 * 45.0).collect();
+        let measurements = DirectionMeasurementsWithHistory::from_values(&angles);
+        assert_eq!(measurements.sector_frequency(8), alloc::vec![0.125; 8]);
+        assert_eq!(measurements.sector_frequency(4), alloc::vec![0.25; 4]);
+    }
+
+    #[test]
+    fn sector_frequency_wraps_around_north() {
+        let measurements =
+            DirectionMeasurementsWithHistory::from_values(&[350.0, 10.0, 90.0, 95.0]);
+        assert_eq!(
+            measurements.sector_frequency(4),
+            alloc::vec![0.5, 0.5, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn sector_frequency_of_empty() {
+        let frequencies = DirectionMeasurementsWithHistory::new().sector_frequency(4);
+        assert_eq!(frequencies.len(), 4);
+        assert!(frequencies.iter().all(|frequency| frequency.is_nan()));
+        assert!(
+            DirectionMeasurementsWithHistory::new()
+                .sector_frequency(0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn sector_mean_resultant_length() {
+        let antipodal = DirectionMeasurementsWithHistory::from_values(&[0.0, 180.0]);
+        assert!(antipodal.sector_mean_resultant_length(1)[0] < 1e-12);
+
+        let measurements =
+            DirectionMeasurementsWithHistory::from_values(&[95.0, 95.0, 95.0, 350.0, 30.0]);
+        let lengths = measurements.sector_mean_resultant_length(4);
+        assert_eq!(lengths.len(), 4);
+        assert!((lengths[0] - 20f64.to_radians().cos()).abs() < 1e-12);
+        assert!((lengths[1] - 1.0).abs() < 1e-12);
+        assert!(lengths[2].is_nan() && lengths[3].is_nan());
+        assert!(measurements.sector_mean_resultant_length(0).is_empty());
+    }
+
+    #[test]
+    fn first_passage_of_veering_wind() {
+        // veering clockwise from 350° in 12° steps, while the arc covered so far is shorter than
+        // a full turn the running average is its midpoint, 350° + 6° per step
+        let angles: Vec<f64> = (0..31)
+            .map(|i| normalize_angle(350.0 + i as f64 * 12.0))
+            .collect();
+        let measurements = DirectionMeasurementsWithHistory::from_values(&angles);
+        assert_eq!(measurements.first_passage_direction(90.0, 5.0), Some(16));
+        assert_eq!(measurements.first_passage_direction(150.0, 12.0), Some(25));
+        assert_eq!(measurements.first_passage_direction(0.0, 15.0), Some(0));
+        assert_eq!(measurements.first_passage_direction(270.0, 30.0), None);
+
+        // a single sample in the band does not move the average there
+        let outlier = DirectionMeasurementsWithHistory::from_values(&[0.0, 5.0, 355.0, 90.0, 0.0]);
+        assert_eq!(outlier.first_passage_direction(90.0, 10.0), None);
+        assert_eq!(
+            DirectionMeasurementsWithHistory::new().first_passage_direction(0.0, 180.0),
+            None
+        );
+    }
+
+    #[test]
+    fn count_in_sector() {
+        let measurements =
+            DirectionMeasurementsWithHistory::from_values(&[350.0, 0.0, 10.0, 100.0]);
+        assert_eq!(measurements.count_in_sector(0.0, 45.0), 3);
+        assert_eq!(measurements.count_in_sector(360.0, 10.0), 3);
+        assert_eq!(measurements.count_in_sector(90.0, 10.0), 1);
+        assert_eq!(measurements.count_in_sector(180.0, 45.0), 0);
+        assert_eq!(measurements.count_in_sector(0.0, 180.0), 4);
+        assert_eq!(
+            DirectionMeasurementsWithHistory::new().count_in_sector(0.0, 180.0),
+            0
+        );
+    }
+
+    #[test]
+    fn dominant_sector() {
+        let angles: Vec<f64> = (0..7)
+            .map(|i| 300.0 + i as f64 * 5.0)
+            .chain([340.0, 355.0])
+            .collect();
+        let measurements = DirectionMeasurementsWithHistory::from_values(&angles);
+        assert_eq!(measurements.dominant_sector(8), (292.5, 337.5));
+        assert_eq!(measurements.dominant_sector(4), (315.0, 45.0));
+    }
+
+    #[test]
+    fn dominant_sector_tie() {
+        let measurements = DirectionMeasurementsWithHistory::from_values(&[0.0, 90.0, 180.0]);
+        assert_eq!(measurements.dominant_sector(4), (45.0, 135.0));
+    }
+
+    #[test]
+    fn dominant_sector_of_empty() {
+        let (start, end) = DirectionMeasurementsWithHistory::new().dominant_sector(8);
+        assert!(start.is_nan() && end.is_nan());
+    }
+
+    #[test]
+    fn median_of_skewed_angles() {
+        let measurements =
+            DirectionMeasurementsWithHistory::from_values(&[0.0, 10.0, 20.0, 30.0, 120.0]);
+        assert_eq!(measurements.median_direction(), 20.0);
+        assert!(measurements.average_direction() > measurements.median_direction());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::WindDirectionMeasurements;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// Writes one angle in degrees per line with two decimals and no header, the polar format read by
+/// most plotting tools. An empty slice gives an empty file.
+pub fn to_polar_csv(angles: &[f64], path: &Path) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for angle in angles {
+        writeln!(writer, "{angle:.2}")?;
+    }
+    writer.flush()
+}
+
+/// Reads a file written by [`to_polar_csv`], blank lines are skipped and an empty file gives an
+/// empty accumulator. A line that is not a number fails with [`io::ErrorKind::InvalidData`].
+pub fn from_polar_csv(path: &Path) -> io::Result<WindDirectionMeasurements> {
+    let mut measurements = WindDirectionMeasurements::new();
+    for (index, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let angle_degrees: f64 = line.parse().map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid angle on line {}", index + 1),
+            )
+        })?;
+        measurements.add_measurement(angle_degrees);
+    }
+    Ok(measurements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("viater-{}-{name}.csv", std::process::id()))
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let path = temp_path("round-trip");
+        let angles = [350.0, 10.0, 20.0, 200.0, 275.0, 5.0, 45.5, 310.25];
+        to_polar_csv(&angles, &path).unwrap();
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .starts_with("350.00\n10.00\n")
+        );
+        let measurements = from_polar_csv(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let expected = WindDirectionMeasurements::from_values(&angles);
+        assert_eq!(measurements.count(), expected.count());
+        assert!((measurements.average_direction() - expected.average_direction()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_csv() {
+        let path = temp_path("empty");
+        to_polar_csv(&[], &path).unwrap();
+        let measurements = from_polar_csv(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(measurements.is_empty());
+    }
+
+    #[test]
+    fn invalid_csv() {
+        let path = temp_path("invalid");
+        std::fs::write(&path, "10\n\nnorth\n").unwrap();
+        let error = from_polar_csv(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(from_polar_csv(&temp_path("missing")).is_err());
+    }
+}
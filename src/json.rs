@@ -0,0 +1,133 @@
+use crate::{ParseError, WindDirectionMeasurements};
+use alloc::format;
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+fn json_number(value: f64) -> String {
+    // JSON has no NaN, the statistics of an empty accumulator become `null`
+    if value.is_finite() {
+        format!("{value}")
+    } else {
+        String::from("null")
+    }
+}
+
+impl WindDirectionMeasurements {
+    /// Summary as a JSON object,
+    /// `{"count":N,"mean_direction":X,"std_dev":Y,"mean_resultant_length":Z}`, with `null` for
+    /// undefined statistics. Numbers are written with enough digits to parse back exactly.
+    pub fn to_json_object(&self) -> String {
+        format!(
+            "{{\"count\":{},\"mean_direction\":{},\"std_dev\":{},\"mean_resultant_length\":{}}}",
+            self.count,
+            json_number(self.average_direction()),
+            json_number(self.standard_deviation()),
+            json_number(self.mean_resultant_length())
+        )
+    }
+
+    /// Parses the output of [`Self::to_json_object`]. This is not a general JSON parser: it
+    /// expects a flat object of numbers (or `null`) and ignores unknown keys.
+    ///
+    /// The accumulator is rebuilt from the count, mean direction and mean resultant length like
+    /// [`Self::from_raw`], so the first-moment statistics are preserved but the second moments
+    /// (skewness, kurtosis) are lost.
+    pub fn from_json_object(s: &str) -> Result<Self, ParseError> {
+        let body = s
+            .trim()
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+            .ok_or(ParseError::InvalidJson)?;
+        let mut count = None;
+        let mut mean_direction = None;
+        let mut mean_resultant_length = None;
+        for member in body.split(',') {
+            let (key, value) = member.split_once(':').ok_or(ParseError::InvalidJson)?;
+            let key = key
+                .trim()
+                .strip_prefix('"')
+                .and_then(|rest| rest.strip_suffix('"'))
+                .ok_or(ParseError::InvalidJson)?;
+            let value = value.trim();
+            let number = || -> Result<f64, ParseError> {
+                if value == "null" {
+                    Ok(f64::NAN)
+                } else {
+                    value.parse().map_err(|_| ParseError::InvalidJson)
+                }
+            };
+            match key {
+                "count" => count = Some(value.parse::<u64>().map_err(|_| ParseError::InvalidJson)?),
+                "mean_direction" => mean_direction = Some(number()?),
+                "mean_resultant_length" => mean_resultant_length = Some(number()?),
+                _ => {}
+            }
+        }
+        let count = count.ok_or(ParseError::InvalidJson)?;
+        if count == 0 {
+            return Ok(WindDirectionMeasurements::new());
+        }
+        let mean_rad = mean_direction.ok_or(ParseError::InvalidJson)?.to_radians();
+        let resultant = count as f64 * mean_resultant_length.ok_or(ParseError::InvalidJson)?;
+        Ok(WindDirectionMeasurements::from_raw(
+            count,
+            resultant * mean_rad.sin(),
+            resultant * mean_rad.cos(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let measurements =
+            WindDirectionMeasurements::from_values(&[350.0, 10.0, 20.0, 200.0, 275.0, 5.0]);
+        let json = measurements.to_json_object();
+        assert!(json.starts_with("{\"count\":6,\"mean_direction\":"));
+        let parsed = WindDirectionMeasurements::from_json_object(&json).unwrap();
+        assert_eq!(parsed.count(), 6);
+        assert!((parsed.average_direction() - measurements.average_direction()).abs() < 1e-12);
+        assert!((parsed.standard_deviation() - measurements.standard_deviation()).abs() < 1e-9);
+        assert!(
+            (parsed.mean_resultant_length() - measurements.mean_resultant_length()).abs() < 1e-12
+        );
+    }
+
+    #[test]
+    fn empty_json() {
+        let json = WindDirectionMeasurements::new().to_json_object();
+        assert_eq!(
+            json,
+            "{\"count\":0,\"mean_direction\":null,\"std_dev\":null,\"mean_resultant_length\":null}"
+        );
+        assert!(
+            WindDirectionMeasurements::from_json_object(&json)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn invalid_json() {
+        for text in [
+            "",
+            "count: 1",
+            "{\"count\":x}",
+            "{\"mean_direction\":10}",
+            "{\"count\":2,\"mean_direction\":10}",
+        ] {
+            assert_eq!(
+                WindDirectionMeasurements::from_json_object(text),
+                Err(ParseError::InvalidJson)
+            );
+        }
+        let spaced =
+            " { \"count\" : 1 , \"mean_direction\" : 90 , \"mean_resultant_length\" : 1 } ";
+        let parsed = WindDirectionMeasurements::from_json_object(spaced).unwrap();
+        assert!((parsed.average_direction() - 90.0).abs() < 1e-12);
+    }
+}
@@ -1,29 +1,243 @@
-pub struct WindDirectionMeasurements {
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod angles;
+#[cfg(feature = "alloc")]
+mod batch;
+#[cfg(feature = "alloc")]
+mod bins;
+mod builder;
+#[cfg(feature = "alloc")]
+mod clustering;
+mod compass;
+mod concentration;
+#[cfg(target_has_atomic = "64")]
+mod concurrent;
+#[cfg(feature = "alloc")]
+mod density;
+mod ema;
+mod error;
+#[cfg(feature = "alloc")]
+mod history;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "alloc")]
+mod json;
+mod nmea;
+mod paired_stats;
+mod persistence;
+#[cfg(feature = "alloc")]
+mod quantiles;
+#[cfg(feature = "alloc")]
+mod records;
+mod regression;
+#[cfg(feature = "alloc")]
+mod rolling;
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+mod simd;
+#[cfg(feature = "rand")]
+mod simulation;
+mod snapshot;
+mod special;
+mod speed;
+mod strict;
+#[cfg(test)]
+mod test_util;
+#[cfg(feature = "alloc")]
+mod time_series;
+mod time_weighted;
+mod two_sample;
+#[cfg(feature = "alloc")]
+mod uniformity;
+mod units;
+mod verification;
+
+pub use angles::{
+    DegreesMinutesSeconds, angular_difference_signed, angular_distance,
+    from_degrees_minutes_seconds, geographic_to_math, interpolate_direction, math_to_geographic,
+    mathematical_to_meteorological, meteorological_to_mathematical, normalize_angle,
+    to_degrees_minutes_seconds, wrap_to_range,
+};
+#[cfg(feature = "alloc")]
+pub use batch::{batch_mean_direction, batch_standard_deviation};
+#[cfg(feature = "alloc")]
+pub use bins::{DirectionBins, DirectionSpeedAccumulator};
+pub use builder::{ConfiguredDirectionMeasurements, DirectionMeasurementsBuilder};
+#[cfg(feature = "alloc")]
+pub use clustering::k_means_circular;
+pub use compass::{from_compass_notation, to_compass_point};
+pub use concentration::ByConcentration;
+#[cfg(target_has_atomic = "64")]
+pub use concurrent::ConcurrentDirectionMeasurements;
+#[cfg(feature = "alloc")]
+pub use density::circular_kde;
+pub use ema::EmaDirectionMeasurements;
+pub use error::{DirectionError, NmeaError, ParseError};
+#[cfg(feature = "alloc")]
+pub use history::DirectionMeasurementsWithHistory;
+#[cfg(feature = "std")]
+pub use io::{from_polar_csv, to_polar_csv};
+#[cfg(feature = "alloc")]
+pub use paired_stats::circular_cross_correlation;
+pub use paired_stats::{PairedDirectionMeasurements, circular_autocorrelation};
+pub use persistence::prevailing_direction_by_run_length;
+#[cfg(feature = "alloc")]
+pub use quantiles::{circular_iqr, circular_median, circular_percentile, max_gap_degrees};
+#[cfg(feature = "alloc")]
+pub use records::{DirectionMeasurementsWithRecords, DirectionRecord};
+pub use regression::{circular_linear_correlation, circular_regression, circular_trend};
+#[cfg(feature = "alloc")]
+pub use rolling::{
+    RollingDirectionMeasurements, circular_direction_convolution, circular_moving_average,
+};
+#[cfg(feature = "rand")]
+pub use simulation::{sample_von_mises, sample_von_mises_with};
+pub use snapshot::DirectionMeasurementsSnapshot;
+pub use speed::{DirectionMeasurementsSpeedWeighted, mean_direction_weighted_by_speed};
+pub use strict::{AllowOutOfRange, DirectionMeasurementsStrict};
+#[cfg(feature = "alloc")]
+pub use time_series::DirectionTimeSeries;
+pub use time_weighted::TimeWeightedDirectionMeasurements;
+pub use two_sample::{phase_difference, von_mises_overlap, watson_williams_test};
+#[cfg(feature = "alloc")]
+pub use uniformity::{angular_dispersion_index, kuiper_test};
+pub use units::{Degrees, Radians};
+pub use verification::{DirectionForecastVerifier, circular_mae, circular_rmse};
+
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindDirectionMeasurements<F = f64> {
     count: u64,
-    sum_sin_rad: f64,
-    sum_cos_rad: f64,
+    sum_weights: F,
+    sum_sin_rad: F,
+    sum_cos_rad: F,
+    sum_sin_2rad: F,
+    sum_cos_2rad: F,
+}
+
+/// Parameters of the Yamartino standard deviation estimator `σ = arcsin(ε) (1 + b ε³)`, where
+/// `ε = √(1 - R̄²)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct YamartinoConfig {
+    /// Weight of the correction term, `2/√3 - 1` in the Yamartino paper.
+    pub b: f64,
+    /// When `false` only the first-order estimate `arcsin(ε)` is used and `b` is ignored.
+    pub use_correction: bool,
+}
+
+/// The configuration used by [`WindDirectionMeasurements::standard_deviation`].
+impl Default for YamartinoConfig {
+    fn default() -> Self {
+        YamartinoConfig {
+            b: 2.0 / 3.0f64.sqrt() - 1.0, // constant from Yamartino paper
+            use_correction: true,
+        }
+    }
 }
 
 #[cfg(feature = "arrow")]
 use arrow::array::Float64Array;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::str::FromStr;
+use num_traits::Float;
+
+// Conversion of f64 constants is infallible for the primitive float types.
+fn cast<F: Float>(value: f64) -> F {
+    F::from(value).unwrap()
+}
 
+// Constructors are provided for `f64` only so that `WindDirectionMeasurements::new()` does not need
+// a type annotation, other float types can use `Default` or `collect()`.
 impl WindDirectionMeasurements {
     pub fn new() -> Self {
-        WindDirectionMeasurements {
-            count: 0,
-            sum_sin_rad: 0.0,
-            sum_cos_rad: 0.0,
+        WindDirectionMeasurements::default()
+    }
+
+    pub fn from_values(values: &[f64]) -> Self {
+        values.iter().copied().collect()
+    }
+
+    /// Like [`Self::from_values`] but fails on the first NaN or infinite value instead of
+    /// letting it poison the sums.
+    pub fn try_from_values(values: &[f64]) -> Result<Self, DirectionError> {
+        if let Some(index) = values.iter().position(|value| !value.is_finite()) {
+            return Err(DirectionError::InvalidAngle(index));
         }
+        Ok(WindDirectionMeasurements::from_values(values))
     }
 
-    pub fn from_values(values: &Vec<f64>) -> Self {
+    pub fn from_values_radians(values: &[f64]) -> Self {
         let mut measurements = WindDirectionMeasurements::new();
         for &value in values {
-            measurements.add_measurement(value);
+            measurements.add_measurement_radians(value);
         }
         measurements
     }
 
+    /// Adds all angles (in degrees), equivalent to calling [`Self::add_measurement`] in a loop.
+    ///
+    /// When compiled for x86_64 with AVX2 enabled (e.g. `-C target-cpu=native`) four angles are
+    /// processed at a time, sine and cosine are then computed with a vectorized approximation
+    /// that matches the scalar results to about 1 ulp rather than bit for bit.
+    pub fn add_measurements_batch(&mut self, angles_degrees: &[f64]) {
+        #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+        let angles_degrees = {
+            // SAFETY: the `simd` module is only compiled when AVX2 is enabled for the target.
+            let (sums, remainder) = unsafe { simd::accumulate(angles_degrees) };
+            self.count += sums.count;
+            self.sum_weights += sums.count as f64;
+            self.sum_sin_rad += sums.sin;
+            self.sum_cos_rad += sums.cos;
+            self.sum_sin_2rad += sums.sin_2;
+            self.sum_cos_2rad += sums.cos_2;
+            remainder
+        };
+        self.extend(angles_degrees.iter().copied());
+    }
+
+    /// Same as [`Self::from_values`] but accumulates chunks of the slice on the Rayon thread pool
+    /// and merges the partial results. Results match sequential accumulation up to
+    /// floating-point rounding of the summation order.
+    #[cfg(feature = "rayon")]
+    pub fn from_values_parallel(values: &[f64]) -> Self {
+        use rayon::prelude::*;
+        values
+            .par_iter()
+            .fold(
+                WindDirectionMeasurements::new,
+                |mut measurements, &value| {
+                    measurements.add_measurement(value);
+                    measurements
+                },
+            )
+            .reduce(WindDirectionMeasurements::new, |a, b| a + b)
+    }
+
+    /// Builds one accumulator per slice, e.g. one per station, in the same order. With the
+    /// `rayon` feature the slices are processed in parallel on the Rayon thread pool.
+    #[cfg(feature = "alloc")]
+    pub fn batch_from_slices(angle_slices: &[&[f64]]) -> alloc::vec::Vec<Self> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            angle_slices
+                .par_iter()
+                .map(|angles| WindDirectionMeasurements::from_values(angles))
+                .collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            angle_slices
+                .iter()
+                .map(|angles| WindDirectionMeasurements::from_values(angles))
+                .collect()
+        }
+    }
+
     #[cfg(feature = "arrow")]
     pub fn from_arrow(array: &Float64Array) -> Self {
         use arrow::array::ArrayIter;
@@ -36,45 +250,636 @@ impl WindDirectionMeasurements {
         measurements
     }
 
-    pub fn add_measurement(&mut self, angle_degrees: f64) {
+    /// Builds the accumulator without the observations farther than
+    /// `threshold_sigma * standard_deviation()` from the preliminary mean of all values, and
+    /// returns it with the indices of the removed observations in ascending order.
+    #[cfg(feature = "alloc")]
+    pub fn quality_controlled_from_values(
+        values: &[f64],
+        threshold_sigma: f64,
+    ) -> (Self, alloc::vec::Vec<usize>) {
+        let preliminary = WindDirectionMeasurements::from_values(values);
+        let mean = preliminary.average_direction();
+        let max_distance = threshold_sigma * preliminary.standard_deviation();
+        let mut cleaned = WindDirectionMeasurements::new();
+        let mut removed = alloc::vec::Vec::new();
+        for (index, &value) in values.iter().enumerate() {
+            if angular_distance(value, mean) > max_distance {
+                removed.push(index);
+            } else {
+                cleaned.add_measurement(value);
+            }
+        }
+        (cleaned, removed)
+    }
+}
+
+impl<F: Float> WindDirectionMeasurements<F> {
+    /// Restores an accumulator from previously persisted running sums, every measurement is
+    /// assumed to have weight 1. Second order statistics (skewness and kurtosis) are not part of
+    /// the raw state and are `NAN` for restored accumulators.
+    ///
+    /// The sums are taken as-is, values that are inconsistent with `count` (e.g. `|sum_sin_rad|`
+    /// larger than `count`) produce nonsensical statistics.
+    pub fn from_raw(count: u64, sum_sin_rad: F, sum_cos_rad: F) -> Self {
+        WindDirectionMeasurements {
+            count,
+            sum_weights: cast(count as f64),
+            sum_sin_rad,
+            sum_cos_rad,
+            sum_sin_2rad: F::nan(),
+            sum_cos_2rad: F::nan(),
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn reset(&mut self) {
+        *self = WindDirectionMeasurements::default();
+    }
+
+    /// Returns `(count, sum_sin_rad, sum_cos_rad)` and resets the accumulator, e.g. to hand the
+    /// sums of a batch to a consumer while the worker keeps accumulating from zero.
+    pub fn drain(&mut self) -> (u64, F, F) {
+        let drained = (self.count, self.sum_sin_rad, self.sum_cos_rad);
+        self.reset();
+        drained
+    }
+
+    pub fn sum_weights(&self) -> F {
+        self.sum_weights
+    }
+
+    pub fn sum_sin_rad(&self) -> F {
+        self.sum_sin_rad
+    }
+
+    pub fn sum_cos_rad(&self) -> F {
+        self.sum_cos_rad
+    }
+
+    /// Adds a measurement in degrees. Values outside [0, 360) such as -10 or 370 are accepted
+    /// and treated like their [`normalize_angle`] equivalent, since only their sine and cosine
+    /// are accumulated.
+    pub fn add_measurement(&mut self, angle_degrees: F) {
+        self.add_measurement_radians(angle_degrees.to_radians());
+    }
+
+    /// Like [`Self::add_measurement`] but leaves the accumulator untouched and fails when the
+    /// angle is NaN or infinite.
+    pub fn try_add_measurement(&mut self, angle_degrees: F) -> Result<(), DirectionError> {
+        if !angle_degrees.is_finite() {
+            return Err(DirectionError::NonFiniteAngle);
+        }
+        self.add_measurement(angle_degrees);
+        Ok(())
+    }
+
+    pub fn add_measurement_radians(&mut self, angle_rad: F) {
+        self.add_weighted_measurement_radians(angle_rad, F::one());
+    }
+
+    pub fn add_weighted_measurement(&mut self, angle_degrees: F, weight: F) {
+        self.add_weighted_measurement_radians(angle_degrees.to_radians(), weight);
+    }
+
+    pub fn add_weighted_measurement_radians(&mut self, angle_rad: F, weight: F) {
         self.count += 1;
-        self.sum_sin_rad += angle_degrees.to_radians().sin();
-        self.sum_cos_rad += angle_degrees.to_radians().cos();
+        self.sum_weights = self.sum_weights + weight;
+        self.sum_sin_rad = self.sum_sin_rad + weight * angle_rad.sin();
+        self.sum_cos_rad = self.sum_cos_rad + weight * angle_rad.cos();
+        let double_angle_rad = angle_rad + angle_rad;
+        self.sum_sin_2rad = self.sum_sin_2rad + weight * double_angle_rad.sin();
+        self.sum_cos_2rad = self.sum_cos_2rad + weight * double_angle_rad.cos();
+    }
+
+    /// Adds an undirected (axial) measurement where `angle_degrees` and `angle_degrees + 180` are
+    /// the same orientation, read the result with [`Self::average_axial_direction`] and
+    /// [`Self::axial_standard_deviation`].
+    ///
+    /// Axial measurements are stored as doubled angles, mixing them with directional measurements
+    /// in the same accumulator produces meaningless statistics.
+    pub fn add_axial_measurement(&mut self, angle_degrees: F) {
+        self.add_measurement(angle_degrees + angle_degrees);
+    }
+
+    /// Removes a previously added (unweighted) measurement, e.g. when it leaves a sliding window.
+    ///
+    /// The running sums are updated by subtraction, so after many add/remove cycles the
+    /// statistics are only approximately equal to those of a freshly built accumulator
+    /// because of floating-point rounding.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds when there are no measurements left to remove. In release builds
+    /// removing from an empty accumulator is a no-op.
+    pub fn remove_measurement(&mut self, angle_degrees: F) {
+        debug_assert!(self.count > 0, "no measurements left to remove");
+        if self.count == 0 {
+            return;
+        }
+        self.count -= 1;
+        if self.count == 0 {
+            self.reset();
+            return;
+        }
+        let angle_rad = angle_degrees.to_radians();
+        self.sum_weights = self.sum_weights - F::one();
+        self.sum_sin_rad = self.sum_sin_rad - angle_rad.sin();
+        self.sum_cos_rad = self.sum_cos_rad - angle_rad.cos();
+        let double_angle_rad = angle_rad + angle_rad;
+        self.sum_sin_2rad = self.sum_sin_2rad - double_angle_rad.sin();
+        self.sum_cos_2rad = self.sum_cos_2rad - double_angle_rad.cos();
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.sum_weights = self.sum_weights + other.sum_weights;
+        self.sum_sin_rad = self.sum_sin_rad + other.sum_sin_rad;
+        self.sum_cos_rad = self.sum_cos_rad + other.sum_cos_rad;
+        self.sum_sin_2rad = self.sum_sin_2rad + other.sum_sin_2rad;
+        self.sum_cos_2rad = self.sum_cos_2rad + other.sum_cos_2rad;
     }
 
-    pub fn average_direction(&self) -> f64 {
+    /// Reverses [`Self::merge`], removing all measurements of `other` at once, e.g. dropping the
+    /// oldest hourly accumulator from a daily one. The result is only meaningful when `other`
+    /// holds a subset of the measurements in `self`, otherwise the sums describe no actual set of
+    /// angles. Removing everything resets the accumulator, so no rounding residue is left.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds when `other` has more measurements than `self`. In release builds
+    /// the accumulator is reset in that case.
+    pub fn unmerge(&mut self, other: &Self) {
+        debug_assert!(
+            other.count <= self.count,
+            "cannot remove more measurements than were added"
+        );
+        self.count = self.count.saturating_sub(other.count);
         if self.count == 0 {
-            return f64::NAN;
+            self.reset();
+            return;
         }
-        let avg_sin_rad = self.sum_sin_rad / self.count as f64;
-        let avg_cos_rad = self.sum_cos_rad / self.count as f64;
-        let arctan = f64::atan2(avg_sin_rad, avg_cos_rad);
+        self.sum_weights = self.sum_weights - other.sum_weights;
+        self.sum_sin_rad = self.sum_sin_rad - other.sum_sin_rad;
+        self.sum_cos_rad = self.sum_cos_rad - other.sum_cos_rad;
+        self.sum_sin_2rad = self.sum_sin_2rad - other.sum_sin_2rad;
+        self.sum_cos_2rad = self.sum_cos_2rad - other.sum_cos_2rad;
+    }
+
+    /// Accumulator as if `shift_degrees` had been subtracted from every measurement, e.g. to
+    /// correct a vane mounted 15° clockwise from true north. The sums are rotated directly, so
+    /// no raw angles are needed.
+    pub fn align_to_north(&self, shift_degrees: F) -> Self {
+        let (sin_shift, cos_shift) = shift_degrees.to_radians().sin_cos();
+        let (sin_double_shift, cos_double_shift) =
+            (shift_degrees + shift_degrees).to_radians().sin_cos();
+        WindDirectionMeasurements {
+            sum_sin_rad: self.sum_sin_rad * cos_shift - self.sum_cos_rad * sin_shift,
+            sum_cos_rad: self.sum_cos_rad * cos_shift + self.sum_sin_rad * sin_shift,
+            sum_sin_2rad: self.sum_sin_2rad * cos_double_shift
+                - self.sum_cos_2rad * sin_double_shift,
+            sum_cos_2rad: self.sum_cos_2rad * cos_double_shift
+                + self.sum_sin_2rad * sin_double_shift,
+            ..*self
+        }
+    }
+
+    /// Converts measurements of where the wind blows toward into where it comes from, see
+    /// [`mathematical_to_meteorological`].
+    pub fn into_meteorological(self) -> Self {
+        self.rotate_half_turn()
+    }
+
+    /// Converts measurements of where the wind comes from into where it blows toward, see
+    /// [`meteorological_to_mathematical`].
+    pub fn into_mathematical(self) -> Self {
+        self.rotate_half_turn()
+    }
+
+    // `align_to_north(180)` with the exact sine and cosine of a half turn, so converting back and
+    // forth restores the original bits. The doubled angles turn by 360° and do not change.
+    fn rotate_half_turn(self) -> Self {
+        WindDirectionMeasurements {
+            sum_sin_rad: -self.sum_sin_rad,
+            sum_cos_rad: -self.sum_cos_rad,
+            ..self
+        }
+    }
+
+    pub fn average_direction(&self) -> F {
+        if self.sum_weights == F::zero() {
+            return F::nan();
+        }
+        let avg_sin_rad = self.sum_sin_rad / self.sum_weights;
+        let avg_cos_rad = self.sum_cos_rad / self.sum_weights;
+        let arctan = F::atan2(avg_sin_rad, avg_cos_rad);
         let arctan_degrees = arctan.to_degrees();
         // atan2 returns values in the range [-180, 180], so we need to normalize it to [0, 360]
-        if arctan_degrees < 0.0 {
-            arctan_degrees + 360.0
+        if arctan_degrees < F::zero() {
+            arctan_degrees + cast(360.0)
         } else {
             arctan_degrees
         }
     }
 
-    pub fn standard_deviation(&self) -> f64 {
-        if self.count == 0 {
-            return f64::NAN;
+    pub fn average_direction_radians(&self) -> F {
+        if self.sum_weights == F::zero() {
+            return F::nan();
+        }
+        let avg_sin_rad = self.sum_sin_rad / self.sum_weights;
+        let avg_cos_rad = self.sum_cos_rad / self.sum_weights;
+        let arctan = F::atan2(avg_sin_rad, avg_cos_rad);
+        // atan2 returns values in the range [-pi, pi], so we need to normalize it to [0, 2pi]
+        if arctan < F::zero() {
+            arctan + cast(core::f64::consts::TAU)
+        } else {
+            arctan
         }
-        let avg_sin_rad = self.sum_sin_rad / self.count as f64;
-        let avg_cos_rad = self.sum_cos_rad / self.count as f64;
-        let epsilon = f64::sqrt(1.0 - (avg_sin_rad.powi(2) + avg_cos_rad.powi(2)));
-        let arcsin = f64::asin(epsilon);
-        let b = 2.0 / f64::sqrt(3.0) - 1.0; // constant from Yamartino paper
-        let sigma = arcsin * (1.0 + b * epsilon.powi(3));
-        sigma.to_degrees()
+    }
+
+    pub fn standard_deviation(&self) -> F {
+        self.standard_deviation_radians().to_degrees()
+    }
+
+    /// [`Self::average_direction`] as `None` instead of `NAN` when there are no measurements.
+    pub fn average_direction_option(&self) -> Option<F> {
+        (self.sum_weights != F::zero()).then(|| self.average_direction())
+    }
+
+    /// [`Self::standard_deviation`] as `None` instead of `NAN` when there are no measurements.
+    pub fn standard_deviation_option(&self) -> Option<F> {
+        (self.sum_weights != F::zero()).then(|| self.standard_deviation())
+    }
+
+    pub fn standard_deviation_radians(&self) -> F {
+        self.standard_deviation_radians_with_config(&YamartinoConfig::default())
+    }
+
+    /// Standard deviation in degrees using a custom Yamartino estimator, see [`YamartinoConfig`].
+    pub fn standard_deviation_with_config(&self, config: &YamartinoConfig) -> F {
+        self.standard_deviation_radians_with_config(config)
+            .to_degrees()
+    }
+
+    fn standard_deviation_radians_with_config(&self, config: &YamartinoConfig) -> F {
+        if self.sum_weights == F::zero() {
+            return F::nan();
+        }
+        let avg_sin_rad = self.sum_sin_rad / self.sum_weights;
+        let avg_cos_rad = self.sum_cos_rad / self.sum_weights;
+        let epsilon = F::sqrt(F::one() - (avg_sin_rad.powi(2) + avg_cos_rad.powi(2)));
+        let arcsin = F::asin(epsilon);
+        if !config.use_correction {
+            return arcsin;
+        }
+        let b: F = cast(config.b);
+        arcsin * (F::one() + b * epsilon.powi(3))
+    }
+
+    /// Mean orientation in [0, 180) of measurements added with [`Self::add_axial_measurement`].
+    pub fn average_axial_direction(&self) -> F {
+        self.average_direction() / cast(2.0)
+    }
+
+    /// Standard deviation in degrees of measurements added with [`Self::add_axial_measurement`].
+    pub fn axial_standard_deviation(&self) -> F {
+        self.standard_deviation() / cast(2.0)
+    }
+
+    pub fn mean_resultant_length(&self) -> F {
+        if self.sum_weights == F::zero() {
+            return F::nan();
+        }
+        let avg_sin_rad = self.sum_sin_rad / self.sum_weights;
+        let avg_cos_rad = self.sum_cos_rad / self.sum_weights;
+        F::sqrt(avg_sin_rad.powi(2) + avg_cos_rad.powi(2))
+    }
+
+    /// How concentrated the directions are, from 0 for no preferred direction to 1 when all
+    /// measurements coincide, e.g. for alerting thresholds. This is the
+    /// [mean resultant length](Self::mean_resultant_length) clamped to [0, 1], so rounding can
+    /// never push it out of range. `NAN` when empty.
+    pub fn concentration_index(&self) -> F {
+        let length = self.mean_resultant_length();
+        if length.is_nan() {
+            return length;
+        }
+        length.max(F::zero()).min(F::one())
+    }
+
+    /// Maximum likelihood estimate of the von Mises concentration κ, the solution of
+    /// `I1(κ) / I0(κ) = R̄` found by Newton-Raphson. 0 when R̄ is 0, infinite when all
+    /// measurements coincide and `NAN` when empty.
+    pub fn von_mises_kappa(&self) -> F {
+        let r = self.mean_resultant_length().to_f64().unwrap_or(f64::NAN);
+        cast(special::von_mises_kappa(r))
+    }
+
+    /// Entropy in nats of the von Mises distribution fitted with [`Self::von_mises_kappa`],
+    /// `H = ln(2π I0(κ)) - κ I1(κ) / I0(κ)`. At most `ln(2π)` (uniform directions) and
+    /// decreasing with concentration down to negative infinity when all measurements coincide.
+    /// `NAN` when empty.
+    pub fn circular_entropy(&self) -> F {
+        let kappa = self.von_mises_kappa().to_f64().unwrap_or(f64::NAN);
+        if kappa == f64::INFINITY {
+            return F::neg_infinity();
+        }
+        let entropy = core::f64::consts::TAU.ln() + special::ln_bessel_i0(kappa)
+            - kappa * special::bessel_i1_over_i0(kappa);
+        cast(entropy)
+    }
+
+    /// Mean resultant vector `(x, y)`, pointing in the mean direction with a magnitude of
+    /// [`Self::mean_resultant_length`] (`x` along 0°, `y` along 90°).
+    pub fn to_unit_vector(&self) -> (F, F) {
+        if self.sum_weights == F::zero() {
+            return (F::nan(), F::nan());
+        }
+        let direction = self.average_direction_radians();
+        let length = self.mean_resultant_length();
+        (length * direction.cos(), length * direction.sin())
+    }
+
+    // Second trigonometric moment about the mean direction, `(sin part, cos part)`.
+    fn second_central_moment(&self) -> (F, F) {
+        let avg_sin_2rad = self.sum_sin_2rad / self.sum_weights;
+        let avg_cos_2rad = self.sum_cos_2rad / self.sum_weights;
+        let rho_2 = F::sqrt(avg_sin_2rad.powi(2) + avg_cos_2rad.powi(2));
+        let mu_2 = F::atan2(avg_sin_2rad, avg_cos_2rad);
+        let theta = self.average_direction_radians();
+        let difference = mu_2 - (theta + theta);
+        (rho_2 * difference.sin(), rho_2 * difference.cos())
+    }
+
+    /// Circular skewness `b̄₂ = R̄₂ sin(μ₂ - 2θ̄)` (Mardia & Jupp 1999), zero for distributions
+    /// symmetric around the mean direction.
+    pub fn circular_skewness(&self) -> F {
+        if self.sum_weights == F::zero() {
+            return F::nan();
+        }
+        self.second_central_moment().0
+    }
+
+    /// Circular kurtosis `ā₂ = R̄₂ cos(μ₂ - 2θ̄)` (Mardia & Jupp 1999), one when all measurements
+    /// are identical.
+    pub fn circular_kurtosis(&self) -> F {
+        if self.sum_weights == F::zero() {
+            return F::nan();
+        }
+        self.second_central_moment().1
+    }
+
+    /// Heuristic check for two roughly opposite modes, e.g. up- and down-valley winds.
+    ///
+    /// Doubling the angles maps opposite directions onto each other, so such data has a high
+    /// second-moment resultant length `R̄₂` while the first-moment `R̄` is small. Unimodal
+    /// distributions have `R̄₂ ≤ R̄` (e.g. `R̄₂ = R̄⁴` for the wrapped normal), so this returns
+    /// `true` when `R̄₂ > 0.25` and `R̄₂ > R̄`.
+    ///
+    /// This is not a significance test. Modes that are not roughly opposite (e.g. 90° apart)
+    /// cancel in the doubled angles and are not detected, and small samples can trigger it by
+    /// chance. Always `false` when empty or after [`Self::from_raw`], which has no second moments.
+    pub fn bimodal_test(&self) -> bool {
+        if self.sum_weights == F::zero() {
+            return false;
+        }
+        let rho_2 =
+            F::sqrt(self.sum_sin_2rad.powi(2) + self.sum_cos_2rad.powi(2)) / self.sum_weights;
+        rho_2 > cast(0.25) && rho_2 > self.mean_resultant_length()
+    }
+
+    // The significance tests take n from the measurement count, which only matches the weights
+    // that R̄ is divided by when every measurement has weight 1.
+    pub(crate) fn has_unit_weights(&self) -> bool {
+        self.sum_weights == cast(self.count as f64)
+    }
+
+    /// Rayleigh test for circular uniformity, returns `(z_statistic, p_value)`.
+    ///
+    /// Small p-values reject the hypothesis that directions are uniformly distributed. The test
+    /// assumes unit weights, so `(NAN, NAN)` is returned when empty or when weighted measurements
+    /// were added.
+    pub fn rayleigh_test(&self) -> (F, F) {
+        if self.count == 0 || !self.has_unit_weights() {
+            return (F::nan(), F::nan());
+        }
+        let n: F = cast(self.count as f64);
+        let z = n * self.mean_resultant_length().powi(2);
+        let p = F::exp(-z)
+            * (F::one() + (cast::<F>(2.0) * z - z.powi(2)) / (cast::<F>(4.0) * n)
+                - (cast::<F>(24.0) * z - cast::<F>(132.0) * z.powi(2)
+                    + cast::<F>(76.0) * z.powi(3)
+                    - cast::<F>(9.0) * z.powi(4))
+                    / (cast::<F>(288.0) * n.powi(2)));
+        (z, p.max(F::zero()).min(F::one()))
+    }
+
+    /// V-test for concentration around `expected_direction_degrees`, returns
+    /// `(v_statistic, p_value)` with `V = R̄ cos(θ̄ - μ₀)` and the one-sided normal approximation
+    /// of `u = V √(2n)`.
+    ///
+    /// Small p-values reject uniformity in favour of a distribution centered on the expected
+    /// direction. Like [`Self::rayleigh_test`] it assumes unit weights and returns `(NAN, NAN)`
+    /// when empty or when weighted measurements were added.
+    pub fn v_test(&self, expected_direction_degrees: F) -> (F, F) {
+        if self.count == 0 || !self.has_unit_weights() {
+            return (F::nan(), F::nan());
+        }
+        let n: F = cast(self.count as f64);
+        let difference = (self.average_direction() - expected_direction_degrees).to_radians();
+        let v = self.mean_resultant_length() * difference.cos();
+        let u = (v * F::sqrt(cast::<F>(2.0) * n))
+            .to_f64()
+            .unwrap_or(f64::NAN);
+        (v, cast(1.0 - special::standard_normal_cdf(u)))
+    }
+
+    /// Confidence interval `(lower, upper)` in degrees for the mean direction, based on the
+    /// large-sample von Mises approximation from Fisher (1993) "Statistical Analysis of Circular
+    /// Data". The interval is symmetric around `average_direction()` and both bounds are in
+    /// [0, 360), so `lower > upper` when it crosses north.
+    ///
+    /// `confidence` must be in (0, 1), at least 25 measurements are required and all of them must
+    /// have unit weight, otherwise `(NAN, NAN)` is returned.
+    pub fn mean_direction_confidence_interval(&self, confidence: F) -> (F, F) {
+        let confidence = confidence.to_f64().unwrap_or(f64::NAN);
+        if self.count < 25 || !self.has_unit_weights() || !(confidence > 0.0 && confidence < 1.0) {
+            return (F::nan(), F::nan());
+        }
+        let n = self.count as f64;
+        let r = self.mean_resultant_length().to_f64().unwrap_or(f64::NAN);
+        let kappa = special::approximate_kappa(r);
+        let z = special::standard_normal_quantile(0.5 + confidence / 2.0);
+        let half_width = (z / f64::sqrt(n * r * kappa)).to_degrees().min(180.0);
+        let mean = self.average_direction().to_f64().unwrap_or(f64::NAN);
+        (
+            cast(normalize_angle(mean - half_width)),
+            cast(normalize_angle(mean + half_width)),
+        )
+    }
+}
+
+/// Parses a comma-separated list of angles in degrees such as `"0, 90,180"`, an empty string gives
+/// an empty accumulator.
+impl FromStr for WindDirectionMeasurements {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut measurements = WindDirectionMeasurements::new();
+        if s.trim().is_empty() {
+            return Ok(measurements);
+        }
+        for (index, token) in s.split(',').enumerate() {
+            let angle_degrees = token
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| ParseError::InvalidNumber { index })?;
+            measurements.add_measurement(angle_degrees);
+        }
+        Ok(measurements)
     }
 }
 
-impl Default for WindDirectionMeasurements {
+impl<F: Float> Default for WindDirectionMeasurements<F> {
     fn default() -> Self {
-        WindDirectionMeasurements::new()
+        WindDirectionMeasurements {
+            count: 0,
+            sum_weights: F::zero(),
+            sum_sin_rad: F::zero(),
+            sum_cos_rad: F::zero(),
+            sum_sin_2rad: F::zero(),
+            sum_cos_2rad: F::zero(),
+        }
+    }
+}
+
+/// Formats as `mean=XXX.X° σ=XX.X° (n=NNN)`, or `empty` when there are no measurements.
+impl<F: Float + fmt::Display> fmt::Display for WindDirectionMeasurements<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "empty");
+        }
+        write!(
+            f,
+            "mean={:.1}° σ={:.1}° (n={})",
+            self.average_direction(),
+            self.standard_deviation(),
+            self.count
+        )
+    }
+}
+
+impl<F: Float + fmt::Debug> fmt::Debug for WindDirectionMeasurements<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindDirectionMeasurements")
+            .field("count", &self.count)
+            .field("sum_weights", &self.sum_weights)
+            .field("sum_sin_rad", &self.sum_sin_rad)
+            .field("sum_cos_rad", &self.sum_cos_rad)
+            .field("sum_sin_2rad", &self.sum_sin_2rad)
+            .field("sum_cos_2rad", &self.sum_cos_2rad)
+            .field("average_direction", &self.average_direction())
+            .field("standard_deviation", &self.standard_deviation())
+            .finish()
+    }
+}
+
+// Exact bitwise comparison, the accumulator state is deterministic for a given input sequence.
+impl<F: Float> PartialEq for WindDirectionMeasurements<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count
+            && self.sum_weights.integer_decode() == other.sum_weights.integer_decode()
+            && self.sum_sin_rad.integer_decode() == other.sum_sin_rad.integer_decode()
+            && self.sum_cos_rad.integer_decode() == other.sum_cos_rad.integer_decode()
+            && self.sum_sin_2rad.integer_decode() == other.sum_sin_2rad.integer_decode()
+            && self.sum_cos_2rad.integer_decode() == other.sum_cos_2rad.integer_decode()
+    }
+}
+
+// The bitwise comparison is reflexive, unlike float `==`.
+impl<F: Float> Eq for WindDirectionMeasurements<F> {}
+
+/// Hashes the same bit-level representation that `PartialEq` compares. NaN sums (e.g. after
+/// [`WindDirectionMeasurements::from_raw`]) are only equal, and hash the same, when their bit
+/// patterns match, so NaNs produced by different computations may not deduplicate.
+impl<F: Float> Hash for WindDirectionMeasurements<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.count.hash(state);
+        self.sum_weights.integer_decode().hash(state);
+        self.sum_sin_rad.integer_decode().hash(state);
+        self.sum_cos_rad.integer_decode().hash(state);
+        self.sum_sin_2rad.integer_decode().hash(state);
+        self.sum_cos_2rad.integer_decode().hash(state);
+    }
+}
+
+impl<F: Float> FromIterator<F> for WindDirectionMeasurements<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut measurements = WindDirectionMeasurements::default();
+        measurements.extend(iter);
+        measurements
+    }
+}
+
+impl<F: Float> Extend<F> for WindDirectionMeasurements<F> {
+    fn extend<I: IntoIterator<Item = F>>(&mut self, iter: I) {
+        for angle_degrees in iter {
+            self.add_measurement(angle_degrees);
+        }
+    }
+}
+
+impl<F: Float> Extend<F> for &mut WindDirectionMeasurements<F> {
+    fn extend<I: IntoIterator<Item = F>>(&mut self, iter: I) {
+        (**self).extend(iter);
+    }
+}
+
+/// Combines two accumulators as if all their measurements were added to a single one, see
+/// [`WindDirectionMeasurements::merge`].
+impl<F: Float> Add for WindDirectionMeasurements<F> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self.merge(&other);
+        self
+    }
+}
+
+/// Adds all measurements of another accumulator in place, see [`WindDirectionMeasurements::merge`].
+impl<F: Float> AddAssign for WindDirectionMeasurements<F> {
+    fn add_assign(&mut self, other: Self) {
+        self.merge(&other);
+    }
+}
+
+/// Removes the measurements of another accumulator, which must be a subset of this one, see
+/// [`WindDirectionMeasurements::unmerge`].
+impl<F: Float> Sub for WindDirectionMeasurements<F> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self.unmerge(&other);
+        self
+    }
+}
+
+/// Removes the measurements of another accumulator in place, see
+/// [`WindDirectionMeasurements::unmerge`].
+impl<F: Float> SubAssign for WindDirectionMeasurements<F> {
+    fn sub_assign(&mut self, other: Self) {
+        self.unmerge(&other);
     }
 }
 
@@ -149,6 +954,762 @@ mod tests {
         ); // Case explored in Yamartino paper
     }
 
+    #[test]
+    fn from_values_accepts_slices_and_vectors() {
+        let from_array = WindDirectionMeasurements::from_values(&[0.0, 90.0]);
+        let values: Vec<f64> = (0..2).map(|i| i as f64 * 90.0).collect();
+        let from_vec = WindDirectionMeasurements::from_values(&values);
+        assert_eq!(from_array, from_vec);
+        assert_eq!(from_array.average_direction(), 45.0);
+    }
+
+    #[test]
+    fn out_of_range_measurements() {
+        let out_of_range = WindDirectionMeasurements::from_values(&[-10.0, 370.0, 720.0]);
+        let normalized = WindDirectionMeasurements::from_values(&[350.0, 10.0, 0.0]);
+        assert!((out_of_range.average_direction() - normalized.average_direction()).abs() < 1e-9);
+        assert!((out_of_range.standard_deviation() - normalized.standard_deviation()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn single_measurement_radians() {
+        let mut measurements = WindDirectionMeasurements::new();
+        measurements.add_measurement_radians(std::f64::consts::FRAC_PI_2);
+        assert_eq!(measurements.average_direction(), 90.0);
+        assert_eq!(measurements.standard_deviation(), 0.0);
+    }
+
+    #[test]
+    fn full_circle_measurements_radians() {
+        let values: Vec<f64> = (0..360).map(|angle| (angle as f64).to_radians()).collect();
+        let measurements = WindDirectionMeasurements::from_values_radians(&values);
+        assert!(
+            measurements.standard_deviation() < 104.0 && measurements.standard_deviation() > 103.0
+        );
+    }
+
+    #[test]
+    fn no_measurements_radians() {
+        let measurements = WindDirectionMeasurements::new();
+        assert!(measurements.average_direction_radians().is_nan());
+        assert!(measurements.standard_deviation_radians().is_nan());
+    }
+
+    #[test]
+    fn outputs_in_radians() {
+        let mut measurements = WindDirectionMeasurements::new();
+        for angle in [350.0, 10.0, 20.0, 200.0, 275.0] {
+            measurements.add_measurement(angle);
+        }
+        let average = measurements.average_direction_radians();
+        let sigma = measurements.standard_deviation_radians();
+        assert!((average - measurements.average_direction().to_radians()).abs() < 1e-12);
+        assert!((sigma - measurements.standard_deviation().to_radians()).abs() < 1e-12);
+        assert!((0.0..std::f64::consts::TAU).contains(&average));
+        assert!((0.0..=std::f64::consts::PI).contains(&sigma));
+    }
+
+    #[test]
+    fn weighted_measurement_with_unit_weight() {
+        let mut weighted = WindDirectionMeasurements::new();
+        let mut unweighted = WindDirectionMeasurements::new();
+        for angle in [0.0, 45.0, 300.0] {
+            weighted.add_weighted_measurement(angle, 1.0);
+            unweighted.add_measurement(angle);
+        }
+        assert_eq!(weighted.average_direction(), unweighted.average_direction());
+        assert_eq!(
+            weighted.standard_deviation(),
+            unweighted.standard_deviation()
+        );
+    }
+
+    #[test]
+    fn weighted_measurements_pull_towards_heavier_side() {
+        let mut measurements = WindDirectionMeasurements::new();
+        for _ in 0..5 {
+            measurements.add_weighted_measurement(10.0, 3.0);
+            measurements.add_weighted_measurement(190.0, 1.0);
+        }
+        assert!((measurements.average_direction() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zero_weights() {
+        let mut measurements = WindDirectionMeasurements::new();
+        measurements.add_weighted_measurement(90.0, 0.0);
+        assert!(measurements.average_direction().is_nan());
+        assert!(measurements.standard_deviation().is_nan());
+    }
+
+    const SPLIT_TEST_VALUES: [f64; 8] = [350.0, 10.0, 20.0, 200.0, 275.0, 5.0, 45.0, 310.0];
+
+    fn assert_same_state(a: &WindDirectionMeasurements, b: &WindDirectionMeasurements) {
+        assert_eq!(a.count, b.count);
+        assert!((a.sum_weights - b.sum_weights).abs() < 1e-12);
+        assert!((a.sum_sin_rad - b.sum_sin_rad).abs() < 1e-12);
+        assert!((a.sum_cos_rad - b.sum_cos_rad).abs() < 1e-12);
+    }
+
+    #[test]
+    fn merge_halves() {
+        let all = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        let (first, second) = SPLIT_TEST_VALUES.split_at(4);
+        let mut merged = WindDirectionMeasurements::from_values(first);
+        merged.merge(&WindDirectionMeasurements::from_values(second));
+        assert_same_state(&merged, &all);
+        assert!((merged.average_direction() - all.average_direction()).abs() < 1e-9);
+        assert!((merged.standard_deviation() - all.standard_deviation()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn add_halves() {
+        let all = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        let (first, second) = SPLIT_TEST_VALUES.split_at(4);
+        let added = WindDirectionMeasurements::from_values(first)
+            + WindDirectionMeasurements::from_values(second);
+        assert_same_state(&added, &all);
+        let mut add_assigned = WindDirectionMeasurements::from_values(first);
+        add_assigned += WindDirectionMeasurements::from_values(second);
+        assert_same_state(&add_assigned, &all);
+    }
+
+    #[test]
+    fn subtract_merged() {
+        let (first, second) = SPLIT_TEST_VALUES.split_at(4);
+        let a = WindDirectionMeasurements::from_values(first);
+        let b = WindDirectionMeasurements::from_values(second);
+        assert_same_state(&((a + b) - b), &a);
+        let mut sub_assigned = a + b;
+        sub_assigned -= a;
+        assert_same_state(&sub_assigned, &b);
+        assert!((sub_assigned.average_direction() - b.average_direction()).abs() < 1e-9);
+        assert!((sub_assigned.standard_deviation() - b.standard_deviation()).abs() < 1e-9);
+        assert_eq!(a - a, WindDirectionMeasurements::new());
+    }
+
+    fn extend_generic<E: Extend<f64>>(mut target: E, values: &[f64]) {
+        target.extend(values.iter().copied());
+    }
+
+    #[test]
+    fn extend_chained_and_by_reference() {
+        let (first, second) = SPLIT_TEST_VALUES.split_at(3);
+        let mut chained = WindDirectionMeasurements::new();
+        chained.extend(first.iter().copied());
+        chained.extend(second.iter().copied());
+        let mut concatenated = WindDirectionMeasurements::new();
+        concatenated.extend(first.iter().chain(second).copied());
+        assert_eq!(chained, concatenated);
+
+        let mut by_reference = WindDirectionMeasurements::new();
+        extend_generic(&mut by_reference, first);
+        extend_generic(&mut by_reference, second);
+        assert_eq!(by_reference, concatenated);
+    }
+
+    #[test]
+    fn addition_is_associative() {
+        let a = WindDirectionMeasurements::from_values(&[350.0, 10.0, 20.0]);
+        let b = WindDirectionMeasurements::from_values(&[200.0, 275.0]);
+        let c = WindDirectionMeasurements::from_values(&[5.0, 45.0, 310.0]);
+        let left = (a + b) + c;
+        let right = a + (b + c);
+        assert_same_state(&left, &right);
+        assert!((left.average_direction() - right.average_direction()).abs() < 1e-9);
+        assert_same_state(
+            &left,
+            &WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES),
+        );
+    }
+
+    #[test]
+    fn collect_from_iterator() {
+        let collected: WindDirectionMeasurements = SPLIT_TEST_VALUES.iter().copied().collect();
+        let mut sequential = WindDirectionMeasurements::new();
+        for &angle in &SPLIT_TEST_VALUES {
+            sequential.add_measurement(angle);
+        }
+        assert_same_state(&collected, &sequential);
+        assert_eq!(
+            collected.average_direction(),
+            sequential.average_direction()
+        );
+    }
+
+    #[test]
+    fn collect_from_empty_iterator() {
+        let collected: WindDirectionMeasurements = std::iter::empty().collect();
+        assert_eq!(collected.count, 0);
+        assert!(collected.average_direction().is_nan());
+    }
+
+    #[test]
+    fn extend_measurements() {
+        let (first, second) = SPLIT_TEST_VALUES.split_at(4);
+        let mut extended: WindDirectionMeasurements = first.iter().copied().collect();
+        extended.extend(second.iter().copied());
+        extended.extend(std::iter::empty());
+        let all: WindDirectionMeasurements = SPLIT_TEST_VALUES.iter().copied().collect();
+        assert_same_state(&extended, &all);
+    }
+
+    #[test]
+    fn axial_measurements() {
+        let empty = WindDirectionMeasurements::new();
+        assert!(empty.average_axial_direction().is_nan());
+        assert!(empty.axial_standard_deviation().is_nan());
+
+        let mut measurements = WindDirectionMeasurements::new();
+        measurements.add_axial_measurement(10.0);
+        measurements.add_axial_measurement(190.0);
+        assert!((measurements.average_axial_direction() - 10.0).abs() < 1e-9);
+        assert!(measurements.axial_standard_deviation().abs() < 1e-6);
+
+        let mut measurements = WindDirectionMeasurements::new();
+        measurements.add_axial_measurement(175.0);
+        measurements.add_axial_measurement(15.0);
+        assert!((measurements.average_axial_direction() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mean_resultant_length() {
+        assert!(
+            WindDirectionMeasurements::new()
+                .mean_resultant_length()
+                .is_nan()
+        );
+        let mut measurements = WindDirectionMeasurements::new();
+        measurements.add_measurement(123.0);
+        assert!((measurements.mean_resultant_length() - 1.0).abs() < 1e-12);
+        let full_circle: WindDirectionMeasurements = (0..360).map(|angle| angle as f64).collect();
+        assert!(full_circle.mean_resultant_length() < 1e-12);
+    }
+
+    #[test]
+    fn concentration_index() {
+        assert!(
+            WindDirectionMeasurements::new()
+                .concentration_index()
+                .is_nan()
+        );
+        let identical = WindDirectionMeasurements::from_values(&[0.1; 1000]);
+        assert!(identical.concentration_index() <= 1.0);
+        assert!((identical.concentration_index() - 1.0).abs() < 1e-12);
+        let opposite = WindDirectionMeasurements::from_values(&[90.0, 270.0]);
+        assert!(opposite.concentration_index() >= 0.0);
+        assert!(opposite.concentration_index() < 1e-12);
+        let spread = WindDirectionMeasurements::from_values(&[0.0, 90.0]);
+        assert!((spread.concentration_index() - 0.5f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn remove_measurements() {
+        let angles: Vec<f64> = (0..100).map(|i| (i * 37 % 360) as f64).collect();
+        let mut measurements = WindDirectionMeasurements::from_values(&angles);
+        for &angle in &angles[..50] {
+            measurements.remove_measurement(angle);
+        }
+        let remaining = WindDirectionMeasurements::from_values(&angles[50..]);
+        assert_same_state(&measurements, &remaining);
+        assert!((measurements.average_direction() - remaining.average_direction()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn remove_last_measurement() {
+        let mut measurements = WindDirectionMeasurements::new();
+        measurements.add_measurement(42.0);
+        measurements.remove_measurement(42.0);
+        assert_eq!(measurements.count, 0);
+        assert!(measurements.average_direction().is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn remove_from_empty() {
+        let mut measurements = WindDirectionMeasurements::new();
+        measurements.remove_measurement(42.0);
+    }
+
+    #[test]
+    fn rayleigh_test() {
+        let (z, p) = WindDirectionMeasurements::new().rayleigh_test();
+        assert!(z.is_nan() && p.is_nan());
+
+        let uniform: WindDirectionMeasurements = (0..360).map(|angle| angle as f64).collect();
+        let (_, p) = uniform.rayleigh_test();
+        assert!(p > 0.99);
+
+        let identical: WindDirectionMeasurements = std::iter::repeat_n(75.0, 30).collect();
+        let (z, p) = identical.rayleigh_test();
+        assert!((z - 30.0).abs() < 1e-9);
+        assert!(p < 1e-6);
+
+        let mut weighted = identical;
+        weighted.add_weighted_measurement(80.0, 2.5);
+        let (z, p) = weighted.rayleigh_test();
+        assert!(z.is_nan() && p.is_nan());
+    }
+
+    #[test]
+    fn clone_and_compare() {
+        let measurements = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        let copied = measurements;
+        assert_eq!(measurements.clone(), copied);
+        let mut modified = copied;
+        modified.add_measurement(0.0);
+        assert_ne!(modified, measurements);
+        assert!(format!("{measurements:?}").contains("count: 8"));
+    }
+
+    #[test]
+    fn single_precision_measurements() {
+        let mut measurements = WindDirectionMeasurements::<f32>::default();
+        measurements.add_measurement(20.0);
+        measurements.add_measurement(40.0);
+        assert!((measurements.average_direction() - 30.0).abs() < 1e-3);
+        assert!((measurements.standard_deviation() - 10.0).abs() < 1.0);
+
+        let full_circle: WindDirectionMeasurements<f32> =
+            (0..360).map(|angle| angle as f32).collect();
+        assert!(
+            full_circle.standard_deviation() < 104.0 && full_circle.standard_deviation() > 103.0
+        );
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        let measurements = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        assert_eq!(measurements.count(), 8);
+        assert_eq!(measurements.sum_weights(), 8.0);
+        let restored = WindDirectionMeasurements::from_raw(
+            measurements.count(),
+            measurements.sum_sin_rad(),
+            measurements.sum_cos_rad(),
+        );
+        assert_eq!(
+            restored.average_direction(),
+            measurements.average_direction()
+        );
+        assert_eq!(
+            restored.standard_deviation(),
+            measurements.standard_deviation()
+        );
+        assert_eq!(restored.sum_sin_rad(), measurements.sum_sin_rad());
+        assert_eq!(restored.sum_cos_rad(), measurements.sum_cos_rad());
+    }
+
+    #[test]
+    fn drain() {
+        let mut measurements = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        let expected = (
+            measurements.count(),
+            measurements.sum_sin_rad(),
+            measurements.sum_cos_rad(),
+        );
+        assert_eq!(measurements.drain(), expected);
+        assert!(measurements.is_empty());
+        assert_eq!(measurements, WindDirectionMeasurements::new());
+        assert_eq!(measurements.drain(), (0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn reset_measurements() {
+        let mut measurements = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        assert!(!measurements.is_empty());
+        assert_eq!(measurements.len(), 8);
+        measurements.reset();
+        assert!(measurements.is_empty());
+        assert_eq!(measurements.len(), 0);
+        assert_eq!(measurements, WindDirectionMeasurements::new());
+        assert!(measurements.average_direction().is_nan());
+    }
+
+    #[test]
+    fn unit_vector() {
+        let (x, y) = WindDirectionMeasurements::new().to_unit_vector();
+        assert!(x.is_nan() && y.is_nan());
+
+        let measurements = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        let (x, y) = measurements.to_unit_vector();
+        let angle = f64::atan2(y, x).to_degrees().rem_euclid(360.0);
+        assert!((angle - measurements.average_direction()).abs() < 1e-9);
+        assert!((f64::hypot(x, y) - measurements.mean_resultant_length()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn skewness_and_kurtosis() {
+        let empty = WindDirectionMeasurements::new();
+        assert!(empty.circular_skewness().is_nan());
+        assert!(empty.circular_kurtosis().is_nan());
+
+        let single = WindDirectionMeasurements::from_values(&[30.0]);
+        assert!(single.circular_skewness().abs() < 1e-12);
+        assert!((single.circular_kurtosis() - 1.0).abs() < 1e-12);
+
+        let two_peaks = WindDirectionMeasurements::from_values(&[40.0, 45.0, 135.0, 140.0]);
+        assert!(two_peaks.circular_skewness().abs() < 1e-12);
+
+        let skewed = WindDirectionMeasurements::from_values(&[0.0, 0.0, 0.0, 0.0, 30.0, 60.0]);
+        let mirrored = WindDirectionMeasurements::from_values(&[0.0, 0.0, 0.0, 0.0, 330.0, 300.0]);
+        assert!(skewed.circular_skewness().abs() > 0.05);
+        assert!((skewed.circular_skewness() + mirrored.circular_skewness()).abs() < 1e-12);
+        assert!(skewed.circular_kurtosis() < single.circular_kurtosis());
+    }
+
+    #[test]
+    fn v_test() {
+        let (v, p) = WindDirectionMeasurements::new().v_test(90.0);
+        assert!(v.is_nan() && p.is_nan());
+
+        let clustered = WindDirectionMeasurements::from_values(&[80.0, 85.0, 90.0, 95.0, 100.0]);
+        let (v, p) = clustered.v_test(90.0);
+        assert!(v > 0.9);
+        assert!(p < 0.01);
+
+        let (v, p) = clustered.v_test(0.0);
+        assert!(v.abs() < 1e-9);
+        assert!(p > 0.05);
+
+        let mut weighted = clustered;
+        weighted.add_weighted_measurement(90.0, 0.5);
+        let (v, p) = weighted.v_test(90.0);
+        assert!(v.is_nan() && p.is_nan());
+    }
+
+    fn confidence_interval_width(measurements: &WindDirectionMeasurements, confidence: f64) -> f64 {
+        let (lower, upper) = measurements.mean_direction_confidence_interval(confidence);
+        (upper - lower).rem_euclid(360.0)
+    }
+
+    #[test]
+    fn confidence_interval() {
+        let pattern = [350.0, 355.0, 5.0, 10.0];
+        let small: WindDirectionMeasurements = pattern.iter().copied().cycle().take(40).collect();
+        let large: WindDirectionMeasurements = pattern.iter().copied().cycle().take(400).collect();
+        let (lower, upper) = small.mean_direction_confidence_interval(0.95);
+        assert!(lower > 180.0 && upper < 180.0); // crosses north
+        assert!(confidence_interval_width(&large, 0.95) < confidence_interval_width(&small, 0.95));
+        assert!(confidence_interval_width(&small, 0.99) > confidence_interval_width(&small, 0.95));
+    }
+
+    #[test]
+    fn confidence_interval_requires_samples() {
+        let measurements: WindDirectionMeasurements = std::iter::repeat_n(10.0, 24).collect();
+        let (lower, upper) = measurements.mean_direction_confidence_interval(0.95);
+        assert!(lower.is_nan() && upper.is_nan());
+        let (lower, upper) =
+            WindDirectionMeasurements::new().mean_direction_confidence_interval(0.95);
+        assert!(lower.is_nan() && upper.is_nan());
+        let measurements: WindDirectionMeasurements = std::iter::repeat_n(10.0, 30).collect();
+        let (lower, upper) = measurements.mean_direction_confidence_interval(1.0);
+        assert!(lower.is_nan() && upper.is_nan());
+        let mut weighted = measurements;
+        weighted.add_weighted_measurement(20.0, 3.0);
+        let (lower, upper) = weighted.mean_direction_confidence_interval(0.95);
+        assert!(lower.is_nan() && upper.is_nan());
+    }
+
+    #[test]
+    fn restored_from_raw_keeps_accumulating() {
+        let (first, second) = SPLIT_TEST_VALUES.split_at(4);
+        let checkpoint = WindDirectionMeasurements::from_values(first);
+        let mut restored = WindDirectionMeasurements::from_raw(
+            checkpoint.count(),
+            checkpoint.sum_sin_rad(),
+            checkpoint.sum_cos_rad(),
+        );
+        restored.extend(second.iter().copied());
+        let all = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        assert_eq!(restored.count(), all.count());
+        assert!((restored.average_direction() - all.average_direction()).abs() < 1e-9);
+        assert!((restored.standard_deviation() - all.standard_deviation()).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_set_deduplicates() {
+        use std::collections::HashSet;
+        let mut set = HashSet::new();
+        set.insert(WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES));
+        set.insert(WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES));
+        set.insert(WindDirectionMeasurements::from_values(&[90.0]));
+        set.insert(WindDirectionMeasurements::from_raw(3, 0.5, 0.5));
+        set.insert(WindDirectionMeasurements::from_raw(3, 0.5, 0.5));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn align_to_north() {
+        let measurements = WindDirectionMeasurements::from_values(&[30.0, 45.0, 60.0]);
+        let aligned = measurements.align_to_north(45.0);
+        assert!(
+            aligned
+                .average_direction()
+                .min(360.0 - aligned.average_direction())
+                < 1e-9
+        );
+        assert_eq!(aligned.count(), measurements.count());
+        let shifted = WindDirectionMeasurements::from_values(&[345.0, 0.0, 15.0]);
+        assert_same_state(&aligned, &shifted);
+        assert!((aligned.circular_skewness() - shifted.circular_skewness()).abs() < 1e-12);
+        assert!((aligned.standard_deviation() - measurements.standard_deviation()).abs() < 1e-9);
+        assert!((measurements.align_to_north(-90.0).average_direction() - 135.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn convention_conversion() {
+        let measurements = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        let mathematical = measurements.into_mathematical();
+        assert!(
+            (mathematical.average_direction()
+                - meteorological_to_mathematical(measurements.average_direction()))
+            .abs()
+                < 1e-9
+        );
+        assert_eq!(
+            mathematical.standard_deviation(),
+            measurements.standard_deviation()
+        );
+        assert_eq!(mathematical.into_meteorological(), measurements);
+        assert_eq!(
+            measurements.into_meteorological().into_mathematical(),
+            measurements
+        );
+        assert!(
+            WindDirectionMeasurements::new()
+                .into_meteorological()
+                .average_direction()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn bimodal_test() {
+        let valley = WindDirectionMeasurements::from_values(&[
+            40.0, 45.0, 50.0, 55.0, 220.0, 225.0, 230.0, 235.0, 228.0,
+        ]);
+        assert!(valley.bimodal_test());
+        let unimodal = WindDirectionMeasurements::from_values(&[350.0, 10.0, 20.0, 5.0, 340.0]);
+        assert!(!unimodal.bimodal_test());
+        let uniform: WindDirectionMeasurements = (0..8).map(|i| i as f64 * 45.0).collect();
+        assert!(!uniform.bimodal_test());
+        assert!(!WindDirectionMeasurements::new().bimodal_test());
+        assert!(!WindDirectionMeasurements::from_raw(4, 0.0, 0.1).bimodal_test());
+    }
+
+    #[test]
+    fn option_statistics() {
+        let empty = WindDirectionMeasurements::new();
+        assert_eq!(empty.average_direction_option(), None);
+        assert_eq!(empty.standard_deviation_option(), None);
+        let single = WindDirectionMeasurements::from_values(&[0.0]);
+        assert_eq!(single.average_direction_option(), Some(0.0));
+        assert_eq!(single.standard_deviation_option(), Some(0.0));
+    }
+
+    #[test]
+    fn standard_deviation_with_config() {
+        let measurements = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        assert_eq!(
+            measurements.standard_deviation_with_config(&YamartinoConfig::default()),
+            measurements.standard_deviation()
+        );
+        let full_circle = WindDirectionMeasurements::from_values(&[0.0, 90.0, 180.0, 270.0]);
+        let uncorrected = YamartinoConfig {
+            use_correction: false,
+            ..YamartinoConfig::default()
+        };
+        assert!((full_circle.standard_deviation_with_config(&uncorrected) - 90.0).abs() < 1e-6);
+        assert!(
+            full_circle.standard_deviation_with_config(&uncorrected)
+                < full_circle.standard_deviation()
+        );
+        let custom = YamartinoConfig {
+            b: 0.5,
+            use_correction: true,
+        };
+        assert!((full_circle.standard_deviation_with_config(&custom) - 135.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn von_mises_kappa() {
+        assert!(WindDirectionMeasurements::new().von_mises_kappa().is_nan());
+        let opposite = WindDirectionMeasurements::from_values(&[0.0, 180.0, 90.0, 270.0]);
+        assert!(opposite.von_mises_kappa().abs() < 1e-9);
+        let spread = WindDirectionMeasurements::from_values(&[0.0, 90.0]).von_mises_kappa();
+        let narrow = WindDirectionMeasurements::from_values(&[0.0, 10.0]).von_mises_kappa();
+        let tight = WindDirectionMeasurements::from_values(&[0.0, 0.01]).von_mises_kappa();
+        assert!(spread < narrow && narrow < tight);
+        assert!(tight > 1e6);
+    }
+
+    #[test]
+    fn circular_entropy() {
+        assert!(WindDirectionMeasurements::new().circular_entropy().is_nan());
+        let uniform: WindDirectionMeasurements = (0..8).map(|i| i as f64 * 45.0).collect();
+        assert!((uniform.circular_entropy() - core::f64::consts::TAU.ln()).abs() < 1e-6);
+        let spread = WindDirectionMeasurements::from_values(&[0.0, 90.0]).circular_entropy();
+        let narrow = WindDirectionMeasurements::from_values(&[0.0, 10.0]).circular_entropy();
+        let tight = WindDirectionMeasurements::from_values(&[0.0, 1.0]).circular_entropy();
+        assert!(uniform.circular_entropy() > spread);
+        assert!(spread > narrow && narrow > tight);
+        let identical = WindDirectionMeasurements::from_values(&[30.0, 30.0]);
+        assert_eq!(identical.circular_entropy(), f64::NEG_INFINITY);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn quality_controlled_from_values() {
+        let values = [350.0, 355.0, 0.0, 5.0, 10.0, 270.0, 358.0, 2.0];
+        let (cleaned, removed) =
+            WindDirectionMeasurements::quality_controlled_from_values(&values, 2.0);
+        assert_eq!(removed, alloc::vec![5]);
+        assert_eq!(
+            cleaned,
+            WindDirectionMeasurements::from_values(&[350.0, 355.0, 0.0, 5.0, 10.0, 358.0, 2.0])
+        );
+        let (all, removed) =
+            WindDirectionMeasurements::quality_controlled_from_values(&values, 10.0);
+        assert!(removed.is_empty());
+        assert_eq!(all, WindDirectionMeasurements::from_values(&values));
+        let (empty, removed) = WindDirectionMeasurements::quality_controlled_from_values(&[], 2.0);
+        assert!(empty.is_empty() && removed.is_empty());
+    }
+
+    #[test]
+    fn try_from_values() {
+        let measurements = WindDirectionMeasurements::try_from_values(&SPLIT_TEST_VALUES).unwrap();
+        assert_eq!(
+            measurements,
+            WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES)
+        );
+        let result = WindDirectionMeasurements::try_from_values(&[10.0, 20.0, f64::NAN, 30.0]);
+        assert_eq!(result, Err(DirectionError::InvalidAngle(2)));
+        let result = WindDirectionMeasurements::try_from_values(&[f64::NEG_INFINITY]);
+        assert_eq!(result, Err(DirectionError::InvalidAngle(0)));
+    }
+
+    #[test]
+    fn try_add_measurement() {
+        let mut measurements = WindDirectionMeasurements::from_values(&[90.0]);
+        assert_eq!(measurements.try_add_measurement(90.0), Ok(()));
+        assert_eq!(
+            measurements.try_add_measurement(f64::INFINITY),
+            Err(DirectionError::NonFiniteAngle)
+        );
+        assert_eq!(
+            measurements,
+            WindDirectionMeasurements::from_values(&[90.0, 90.0])
+        );
+    }
+
+    #[test]
+    fn parse_from_str() {
+        let empty: WindDirectionMeasurements = "".parse().unwrap();
+        assert!(empty.is_empty());
+        let blank: WindDirectionMeasurements = "  ".parse().unwrap();
+        assert!(blank.is_empty());
+        let single: WindDirectionMeasurements = "90".parse().unwrap();
+        assert_eq!(single, WindDirectionMeasurements::from_values(&[90.0]));
+        let spaced: WindDirectionMeasurements = " 0 , 90,180 ,  270 ".parse().unwrap();
+        assert_eq!(
+            spaced,
+            WindDirectionMeasurements::from_values(&[0.0, 90.0, 180.0, 270.0])
+        );
+    }
+
+    #[test]
+    fn parse_invalid_str() {
+        let result = "0, 90, north".parse::<WindDirectionMeasurements>();
+        assert_eq!(result, Err(ParseError::InvalidNumber { index: 2 }));
+        let result = "0,,90".parse::<WindDirectionMeasurements>();
+        assert_eq!(result, Err(ParseError::InvalidNumber { index: 1 }));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(WindDirectionMeasurements::new().to_string(), "empty");
+        let measurements = WindDirectionMeasurements::from_values(&[90.0, 90.0, 90.0]);
+        assert_eq!(measurements.to_string(), "mean=90.0° σ=0.0° (n=3)");
+        let measurements = WindDirectionMeasurements::from_values(&[0.0, 90.0]);
+        assert_eq!(format!("{measurements}"), "mean=45.0° σ=47.5° (n=2)");
+    }
+
+    #[test]
+    fn debug() {
+        let measurements = WindDirectionMeasurements::from_values(&[90.0]);
+        let debug = format!("{measurements:?}");
+        assert!(debug.starts_with("WindDirectionMeasurements { count: 1, sum_weights: 1.0, "));
+        assert!(debug.contains("sum_sin_rad: 1.0"));
+        assert!(debug.ends_with("average_direction: 90.0, standard_deviation: 0.0 }"));
+    }
+
+    #[test]
+    fn add_measurements_batch() {
+        let values: Vec<f64> = (0..1001)
+            .map(|i| (i * 7 % 720) as f64 * 0.5 - 90.0)
+            .collect();
+        let mut batch = WindDirectionMeasurements::new();
+        batch.add_measurements_batch(&values);
+        let sequential = WindDirectionMeasurements::from_values(&values);
+        if cfg!(all(target_arch = "x86_64", target_feature = "avx2")) {
+            assert_eq!(batch.count(), sequential.count());
+            assert_same_state(&batch, &sequential);
+            assert!((batch.circular_skewness() - sequential.circular_skewness()).abs() < 1e-12);
+        } else {
+            assert_eq!(batch, sequential);
+        }
+
+        let mut huge = WindDirectionMeasurements::new();
+        huge.add_measurements_batch(&[1e12, 90.0, 180.0, 270.0]);
+        let expected = WindDirectionMeasurements::from_values(&[1e12, 90.0, 180.0, 270.0]);
+        assert_same_state(&huge, &expected);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn from_values_parallel() {
+        let values: Vec<f64> = (0..100_000).map(|i| (i * 7 % 360) as f64 + 0.25).collect();
+        let sequential = WindDirectionMeasurements::from_values(&values);
+        let parallel = WindDirectionMeasurements::from_values_parallel(&values);
+        assert_eq!(parallel.count(), sequential.count());
+        assert!((parallel.sum_sin_rad() - sequential.sum_sin_rad()).abs() < 1e-8);
+        assert!((parallel.sum_cos_rad() - sequential.sum_cos_rad()).abs() < 1e-8);
+        assert!((parallel.average_direction() - sequential.average_direction()).abs() < 1e-6);
+        assert!(WindDirectionMeasurements::from_values_parallel(&[]).is_empty());
+    }
+
+    #[test]
+    fn batch_from_slices() {
+        let north = [350.0, 10.0];
+        let east = [80.0, 90.0, 100.0];
+        let batch = WindDirectionMeasurements::batch_from_slices(&[&north, &[], &east]);
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch[0], WindDirectionMeasurements::from_values(&north));
+        assert!(batch[1].is_empty());
+        assert_eq!(batch[2], WindDirectionMeasurements::from_values(&east));
+        assert!(WindDirectionMeasurements::batch_from_slices(&[]).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let measurements = WindDirectionMeasurements::from_values(&SPLIT_TEST_VALUES);
+        let json = serde_json::to_string(&measurements).unwrap();
+        let restored: WindDirectionMeasurements = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.count, measurements.count);
+        assert_eq!(
+            restored.average_direction(),
+            measurements.average_direction()
+        );
+        assert_eq!(
+            restored.standard_deviation(),
+            measurements.standard_deviation()
+        );
+    }
+
     #[cfg(feature = "arrow")]
     #[test]
     fn from_empty_arrow() {
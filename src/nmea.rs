@@ -0,0 +1,142 @@
+use crate::{NmeaError, WindDirectionMeasurements};
+
+impl WindDirectionMeasurements {
+    /// Accumulates the wind angles of NMEA 0183 `MWV` (wind speed and angle) sentences such as
+    /// `$WIMWV,214.8,R,0.1,K,A*28`, from any talker. Relative and true angles are both taken as
+    /// they are, sentences flagged as invalid data (status `V`) are skipped. The checksum is
+    /// verified when present, and trailing line endings are ignored.
+    ///
+    /// Fails on the first sentence that is not well-formed NMEA, has a wrong checksum, is a
+    /// different sentence type or has malformed fields, the error holds its index in `sentences`.
+    pub fn from_nmea_sentences(sentences: &[&str]) -> Result<Self, NmeaError> {
+        let mut measurements = WindDirectionMeasurements::new();
+        for (index, sentence) in sentences.iter().enumerate() {
+            if let Some(angle) = parse_mwv_angle(sentence, index)? {
+                measurements.add_measurement(angle);
+            }
+        }
+        Ok(measurements)
+    }
+}
+
+// Wind angle of a valid MWV sentence, `None` when the sentence flags its data as invalid.
+fn parse_mwv_angle(sentence: &str, index: usize) -> Result<Option<f64>, NmeaError> {
+    let body = sentence
+        .trim_end_matches(['\r', '\n'])
+        .strip_prefix('$')
+        .ok_or(NmeaError::UnknownSentence { index })?;
+    let body = match body.split_once('*') {
+        Some((body, checksum)) => {
+            let expected = u8::from_str_radix(checksum, 16)
+                .ok()
+                .filter(|_| checksum.len() == 2 && checksum.bytes().all(|b| b.is_ascii_hexdigit()))
+                .ok_or(NmeaError::InvalidChecksum { index })?;
+            if body.bytes().fold(0, |checksum, byte| checksum ^ byte) != expected {
+                return Err(NmeaError::InvalidChecksum { index });
+            }
+            body
+        }
+        None => body,
+    };
+    let mut fields = body.split(',');
+    let address = fields.next().unwrap_or_default();
+    if address.len() != 5 || !address.bytes().all(|byte| byte.is_ascii_alphanumeric()) {
+        return Err(NmeaError::UnknownSentence { index });
+    }
+    if &address[2..] != "MWV" {
+        return Err(NmeaError::NotWindSentence { index });
+    }
+    let invalid_field = NmeaError::InvalidField { index };
+    let angle = fields
+        .next()
+        .and_then(|angle| angle.parse::<f64>().ok())
+        .filter(|angle| angle.is_finite())
+        .ok_or(invalid_field)?;
+    if !matches!(fields.next(), Some("R" | "T")) {
+        return Err(invalid_field);
+    }
+    // speed and its unit are not needed for the direction
+    let _ = (fields.next(), fields.next());
+    match fields.next() {
+        Some("A") => Ok(Some(angle)),
+        Some("V") => Ok(None),
+        _ => Err(invalid_field),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_wind_sentences() {
+        let measurements = WindDirectionMeasurements::from_nmea_sentences(&[
+            "$WIMWV,10.0,T,5.2,N,A*13\r\n",
+            "$IIMWV,350.0,T,4.0,M,A*3A",
+            "$WIMWV,90.0,R,1.0,K,V*09",
+            "$WIMWV,20.0,R,3.0,K,A",
+        ])
+        .unwrap();
+        assert_eq!(measurements.count(), 3);
+        let expected = WindDirectionMeasurements::from_values(&[10.0, 350.0, 20.0]);
+        assert!((measurements.average_direction() - expected.average_direction()).abs() < 1e-9);
+        assert!(
+            WindDirectionMeasurements::from_nmea_sentences(&[])
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_sentences() {
+        let parse = |sentence| WindDirectionMeasurements::from_nmea_sentences(&[sentence]);
+        assert_eq!(
+            parse("$WIMWV,214.8,R,0.1,K,A*29"),
+            Err(NmeaError::InvalidChecksum { index: 0 })
+        );
+        assert_eq!(
+            parse("$WIMWV,214.8,R,0.1,K,A*"),
+            Err(NmeaError::InvalidChecksum { index: 0 })
+        );
+        // the body checksum is 0x0F, but a sign is not a hex digit
+        assert_eq!(
+            parse("$WIMWV,10.0,R,3,N,A*+F"),
+            Err(NmeaError::InvalidChecksum { index: 0 })
+        );
+        assert_eq!(
+            parse("$WIMWV,214.8,R,0.1,K,A*+F"),
+            Err(NmeaError::InvalidChecksum { index: 0 })
+        );
+        assert_eq!(
+            parse("$GPGGA,123519,4807.038,N*27"),
+            Err(NmeaError::NotWindSentence { index: 0 })
+        );
+        assert_eq!(
+            parse("WIMWV,214.8,R,0.1,K,A"),
+            Err(NmeaError::UnknownSentence { index: 0 })
+        );
+        assert_eq!(
+            parse("$WI,214.8,R,0.1,K,A"),
+            Err(NmeaError::UnknownSentence { index: 0 })
+        );
+        assert_eq!(
+            parse("$WIMWV,north,R,0.1,K,A"),
+            Err(NmeaError::InvalidField { index: 0 })
+        );
+        assert_eq!(
+            parse("$WIMWV,214.8,X,0.1,K,A"),
+            Err(NmeaError::InvalidField { index: 0 })
+        );
+        assert_eq!(
+            parse("$WIMWV,214.8,R,0.1,K"),
+            Err(NmeaError::InvalidField { index: 0 })
+        );
+        assert_eq!(
+            WindDirectionMeasurements::from_nmea_sentences(&[
+                "$WIMWV,214.8,R,0.1,K,A*28",
+                "$GPGGA,123519,4807.038,N*27",
+            ]),
+            Err(NmeaError::NotWindSentence { index: 1 })
+        );
+    }
+}
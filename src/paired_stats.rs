@@ -0,0 +1,243 @@
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// Resultant lengths below this fraction of the count are zero up to the rounding of sin/cos, e.g.
+// sin(180°) is 1.2e-16, and leave the mean direction undefined.
+const UNDEFINED_MEAN_RESULTANT: f64 = 1e-12;
+
+/// Accumulator for pairs of simultaneous directions (e.g. the same instant at two stations),
+/// keeping the joint trigonometric moments needed for their correlation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PairedDirectionMeasurements {
+    count: u64,
+    sum_sin_a: f64,
+    sum_cos_a: f64,
+    sum_sin_b: f64,
+    sum_cos_b: f64,
+    sum_sin_a_sin_a: f64,
+    sum_sin_a_cos_a: f64,
+    sum_cos_a_cos_a: f64,
+    sum_sin_b_sin_b: f64,
+    sum_sin_b_cos_b: f64,
+    sum_cos_b_cos_b: f64,
+    sum_sin_a_sin_b: f64,
+    sum_sin_a_cos_b: f64,
+    sum_cos_a_sin_b: f64,
+    sum_cos_a_cos_b: f64,
+}
+
+impl PairedDirectionMeasurements {
+    pub fn new() -> Self {
+        PairedDirectionMeasurements::default()
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Adds one pair of directions in degrees.
+    pub fn add_pair(&mut self, a: f64, b: f64) {
+        let (sin_a, cos_a) = a.to_radians().sin_cos();
+        let (sin_b, cos_b) = b.to_radians().sin_cos();
+        self.count += 1;
+        self.sum_sin_a += sin_a;
+        self.sum_cos_a += cos_a;
+        self.sum_sin_b += sin_b;
+        self.sum_cos_b += cos_b;
+        self.sum_sin_a_sin_a += sin_a * sin_a;
+        self.sum_sin_a_cos_a += sin_a * cos_a;
+        self.sum_cos_a_cos_a += cos_a * cos_a;
+        self.sum_sin_b_sin_b += sin_b * sin_b;
+        self.sum_sin_b_cos_b += sin_b * cos_b;
+        self.sum_cos_b_cos_b += cos_b * cos_b;
+        self.sum_sin_a_sin_b += sin_a * sin_b;
+        self.sum_sin_a_cos_b += sin_a * cos_b;
+        self.sum_cos_a_sin_b += cos_a * sin_b;
+        self.sum_cos_a_cos_b += cos_a * cos_b;
+    }
+
+    /// Jammalamadaka–SenGupta circular correlation coefficient in [-1, 1],
+    /// `Σ sin(a - ā) sin(b - b̄) / √(Σ sin²(a - ā) Σ sin²(b - b̄))`, with the sums expanded in
+    /// terms of the accumulated moments. `NAN` with fewer than 2 pairs or when either mean
+    /// direction is undefined because the angles balance out (zero resultant up to rounding).
+    pub fn circular_correlation(&self) -> f64 {
+        if self.count < 2 {
+            return f64::NAN;
+        }
+        let min_resultant = UNDEFINED_MEAN_RESULTANT * self.count as f64;
+        if self.sum_sin_a.hypot(self.sum_cos_a) <= min_resultant
+            || self.sum_sin_b.hypot(self.sum_cos_b) <= min_resultant
+        {
+            return f64::NAN;
+        }
+        let (sin_mean_a, cos_mean_a) = self.sum_sin_a.atan2(self.sum_cos_a).sin_cos();
+        let (sin_mean_b, cos_mean_b) = self.sum_sin_b.atan2(self.sum_cos_b).sin_cos();
+        let covariance = self.sum_sin_a_sin_b * cos_mean_a * cos_mean_b
+            - self.sum_sin_a_cos_b * cos_mean_a * sin_mean_b
+            - self.sum_cos_a_sin_b * sin_mean_a * cos_mean_b
+            + self.sum_cos_a_cos_b * sin_mean_a * sin_mean_b;
+        let variance = |sin_sin: f64, sin_cos: f64, cos_cos: f64, sin_mean: f64, cos_mean: f64| {
+            sin_sin * cos_mean * cos_mean - 2.0 * sin_cos * sin_mean * cos_mean
+                + cos_cos * sin_mean * sin_mean
+        };
+        let variance_a = variance(
+            self.sum_sin_a_sin_a,
+            self.sum_sin_a_cos_a,
+            self.sum_cos_a_cos_a,
+            sin_mean_a,
+            cos_mean_a,
+        );
+        let variance_b = variance(
+            self.sum_sin_b_sin_b,
+            self.sum_sin_b_cos_b,
+            self.sum_cos_b_cos_b,
+            sin_mean_b,
+            cos_mean_b,
+        );
+        (covariance / (variance_a * variance_b).sqrt()).clamp(-1.0, 1.0)
+    }
+}
+
+/// Circular autocorrelation of a series of directions in degrees at `lag`, the
+/// [circular correlation](PairedDirectionMeasurements::circular_correlation) of each angle with
+/// the one `lag` samples later. `NAN` when there are fewer than two such pairs
+/// (`angles.len() < lag + 2`) or the correlation is undefined.
+pub fn circular_autocorrelation(angles: &[f64], lag: usize) -> f64 {
+    let mut paired = PairedDirectionMeasurements::new();
+    for (&earlier, &later) in angles.iter().zip(angles.iter().skip(lag)) {
+        paired.add_pair(earlier, later);
+    }
+    paired.circular_correlation()
+}
+
+/// Circular cross-correlation of two direction series in degrees, the
+/// [circular correlation](PairedDirectionMeasurements::circular_correlation) of `a[i]` with
+/// `b[i + lag]` for every lag from `-(a.len() - 1)` to `b.len() - 1`, in that order, so `2n - 1`
+/// values for two series of length `n` with lag 0 in the middle. A peak at a positive lag means
+/// `b` follows `a`. Lags with fewer than two pairs are `NAN`, the result is empty when either
+/// series is.
+#[cfg(feature = "alloc")]
+pub fn circular_cross_correlation(a: &[f64], b: &[f64]) -> alloc::vec::Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return alloc::vec::Vec::new();
+    }
+    let lags = -(a.len() as isize - 1)..b.len() as isize;
+    lags.map(|lag| {
+        let (a, b) = if lag < 0 {
+            (&a[lag.unsigned_abs()..], b)
+        } else {
+            (a, &b[lag as usize..])
+        };
+        let mut paired = PairedDirectionMeasurements::new();
+        for (&a, &b) in a.iter().zip(b) {
+            paired.add_pair(a, b);
+        }
+        paired.circular_correlation()
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::lcg;
+
+    const ANGLES: [f64; 6] = [350.0, 10.0, 25.0, 40.0, 5.0, 300.0];
+
+    #[test]
+    fn too_few_pairs() {
+        let mut paired = PairedDirectionMeasurements::new();
+        assert!(paired.circular_correlation().is_nan());
+        paired.add_pair(10.0, 20.0);
+        assert!(paired.circular_correlation().is_nan());
+    }
+
+    #[test]
+    fn undefined_mean_direction() {
+        let mut paired = PairedDirectionMeasurements::new();
+        paired.add_pair(0.0, 10.0);
+        paired.add_pair(180.0, 30.0);
+        assert!(paired.circular_correlation().is_nan());
+        let mut paired = PairedDirectionMeasurements::new();
+        for (a, b) in [(10.0, 0.0), (20.0, 90.0), (40.0, 180.0), (30.0, 270.0)] {
+            paired.add_pair(a, b);
+        }
+        assert!(paired.circular_correlation().is_nan());
+    }
+
+    #[test]
+    fn perfectly_correlated() {
+        let mut paired = PairedDirectionMeasurements::new();
+        for angle in ANGLES {
+            paired.add_pair(angle, angle + 90.0);
+        }
+        assert!((paired.circular_correlation() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn anti_correlated() {
+        let mut paired = PairedDirectionMeasurements::new();
+        for angle in ANGLES {
+            paired.add_pair(angle, 180.0 - angle);
+        }
+        assert!((paired.circular_correlation() + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn autocorrelation_of_periodic_series() {
+        let cycle = [10.0, 80.0, 200.0, 300.0];
+        let angles: Vec<f64> = cycle.iter().cycle().take(24).copied().collect();
+        assert!((circular_autocorrelation(&angles, 4) - 1.0).abs() < 1e-9);
+        assert!((circular_autocorrelation(&angles, 8) - 1.0).abs() < 1e-9);
+        assert!(circular_autocorrelation(&angles, 2) < 0.5);
+    }
+
+    #[test]
+    fn autocorrelation_with_insufficient_data() {
+        assert!(circular_autocorrelation(&[10.0, 20.0, 30.0], 2).is_nan());
+        assert!(circular_autocorrelation(&[], 0).is_nan());
+        assert!(!circular_autocorrelation(&[10.0, 20.0, 40.0, 30.0], 2).is_nan());
+    }
+
+    #[test]
+    fn cross_correlation_peaks_at_lag() {
+        let mut uniform = lcg(99);
+        let upstream: Vec<f64> = (0..40).map(|_| uniform() * 120.0 + 200.0).collect();
+        // the downstream station sees the same directions two steps later, turned by 30°
+        let downstream: Vec<f64> = [250.0, 260.0]
+            .into_iter()
+            .chain(upstream.iter().map(|angle| angle + 30.0))
+            .take(upstream.len())
+            .collect();
+        let correlation = circular_cross_correlation(&upstream, &downstream);
+        assert_eq!(correlation.len(), 2 * upstream.len() - 1);
+        let lag_zero = upstream.len() - 1;
+        assert!((correlation[lag_zero + 2] - 1.0).abs() < 1e-9);
+        // lags with only a few overlapping pairs can correlate perfectly by chance
+        let max_lag = upstream.len() / 2;
+        let peak = (lag_zero - max_lag..=lag_zero + max_lag)
+            .filter(|&index| !correlation[index].is_nan())
+            .max_by(|&x, &y| correlation[x].total_cmp(&correlation[y]))
+            .unwrap();
+        assert_eq!(peak, lag_zero + 2);
+        assert!(correlation[0].is_nan() && correlation[correlation.len() - 1].is_nan());
+    }
+
+    #[test]
+    fn cross_correlation_of_empty_series() {
+        assert!(circular_cross_correlation(&[], &[10.0]).is_empty());
+        assert!(circular_cross_correlation(&[10.0], &[]).is_empty());
+        assert_eq!(circular_cross_correlation(&[10.0, 20.0], &[5.0]).len(), 2);
+    }
+
+    #[test]
+    fn partially_correlated() {
+        let mut paired = PairedDirectionMeasurements::new();
+        for (index, angle) in ANGLES.iter().enumerate() {
+            let noise = if index % 2 == 0 { 15.0 } else { -15.0 };
+            paired.add_pair(*angle, angle + noise);
+        }
+        let correlation = paired.circular_correlation();
+        assert!(correlation > 0.5 && correlation < 1.0);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{WindDirectionMeasurements, angular_distance};
+
+/// Longest run of consecutive angles in which each step changes the direction by at most
+/// `tolerance_degrees`, returned as `(mean_direction, run_length)`. Ties go to the earliest run,
+/// `None` for empty input. A single angle is a run of length 1.
+///
+/// Only neighbouring angles are compared, so a slow steady veer forms one long run.
+pub fn prevailing_direction_by_run_length(
+    angles: &[f64],
+    tolerance_degrees: f64,
+) -> Option<(f64, usize)> {
+    if angles.is_empty() {
+        return None;
+    }
+    let mut longest = 0..1;
+    let mut start = 0;
+    for (end, pair) in angles.windows(2).enumerate().map(|(i, pair)| (i + 1, pair)) {
+        if angular_distance(pair[0], pair[1]) > tolerance_degrees {
+            start = end;
+        }
+        if end + 1 - start > longest.len() {
+            longest = start..end + 1;
+        }
+    }
+    let mean = WindDirectionMeasurements::from_values(&angles[longest.clone()]).average_direction();
+    Some((mean, longest.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plateau_surrounded_by_noise() {
+        let noise = [10.0, 150.0, 300.0, 45.0, 200.0];
+        let angles: Vec<f64> = noise
+            .iter()
+            .copied()
+            .chain([275.0; 20])
+            .chain(noise.iter().rev().copied())
+            .collect();
+        let (mean, length) = prevailing_direction_by_run_length(&angles, 5.0).unwrap();
+        assert_eq!(length, 20);
+        assert!((mean - 275.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn run_across_north() {
+        let angles = [180.0, 355.0, 358.0, 1.0, 4.0, 90.0, 92.0];
+        let (mean, length) = prevailing_direction_by_run_length(&angles, 3.0).unwrap();
+        assert_eq!(length, 4);
+        assert!((mean - 359.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn short_inputs() {
+        assert_eq!(prevailing_direction_by_run_length(&[], 5.0), None);
+        let (mean, length) = prevailing_direction_by_run_length(&[42.0], 5.0).unwrap();
+        assert_eq!(length, 1);
+        assert!((mean - 42.0).abs() < 1e-9);
+        // no two neighbours are close, the first angle wins the tie
+        let (mean, length) = prevailing_direction_by_run_length(&[0.0, 90.0, 180.0], 5.0).unwrap();
+        assert_eq!(length, 1);
+        assert!(mean.abs() < 1e-9);
+    }
+}
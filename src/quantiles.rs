@@ -0,0 +1,140 @@
+use crate::normalize_angle;
+use alloc::vec::Vec;
+
+/// The `p`-th quantile (`p` in [0, 1]) of the angles in degrees, measured along the arc they
+/// occupy as in [`circular_iqr`], so a cluster around north is not split at 0°. `NAN` when
+/// `angles` is empty or `p` is outside [0, 1].
+pub fn circular_percentile(angles: &[f64], p: f64) -> f64 {
+    if angles.is_empty() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    normalize_angle(linear_quantile(&unwrap_at_largest_gap(angles), p))
+}
+
+/// Same as [`circular_percentile`] with `p = 0.5`.
+pub fn circular_median(angles: &[f64]) -> f64 {
+    circular_percentile(angles, 0.5)
+}
+
+/// Circular interquartile range in degrees, the arc spanned by the middle 50% of the angles,
+/// `Q3 - Q1`. As in Fisher (1993) the quartiles are taken on the arc the angles occupy: the circle
+/// is cut in the middle of the largest gap between neighbouring angles, so that no cluster is
+/// split, and quantiles interpolate linearly between the order statistics from there. `NAN` for
+/// fewer than 4 angles.
+pub fn circular_iqr(angles: &[f64]) -> f64 {
+    if angles.len() < 4 {
+        return f64::NAN;
+    }
+    let unwrapped = unwrap_at_largest_gap(angles);
+    linear_quantile(&unwrapped, 0.75) - linear_quantile(&unwrapped, 0.25)
+}
+
+/// Largest arc in degrees between neighbouring angles on the circle, including the arc across
+/// north, e.g. to check that observations cover all directions. By convention 360 for no angles
+/// and 0 for a single one.
+pub fn max_gap_degrees(angles: &[f64]) -> f64 {
+    match angles.len() {
+        0 => 360.0,
+        1 => 0.0,
+        _ => largest_gap(&sorted_on_circle(angles)).1,
+    }
+}
+
+fn sorted_on_circle(angles: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = angles.iter().map(|&angle| normalize_angle(angle)).collect();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+// `(index, gap)` of the largest gap in non-empty sorted angles, `index` being the angle after it.
+// Ties keep the gap across north.
+fn largest_gap(sorted: &[f64]) -> (usize, f64) {
+    let mut largest = (0, sorted[0] + 360.0 - sorted[sorted.len() - 1]);
+    for (index, pair) in sorted.windows(2).enumerate() {
+        if pair[1] - pair[0] > largest.1 {
+            largest = (index + 1, pair[1] - pair[0]);
+        }
+    }
+    largest
+}
+
+// Sorted angles starting after the largest gap, those past north continued above 360.
+fn unwrap_at_largest_gap(angles: &[f64]) -> Vec<f64> {
+    let mut sorted = sorted_on_circle(angles);
+    let (start, _) = largest_gap(&sorted);
+    sorted.rotate_left(start);
+    let wrapped = sorted.len() - start;
+    for angle in &mut sorted[wrapped..] {
+        *angle += 360.0;
+    }
+    sorted
+}
+
+// Linear interpolation between order statistics (`h = (n - 1) p`) of sorted values.
+fn linear_quantile(sorted: &[f64], p: f64) -> f64 {
+    let h = (sorted.len() - 1) as f64 * p;
+    let lower = h as usize;
+    let upper = (lower + 1).min(sorted.len() - 1);
+    sorted[lower] + (h - lower as f64) * (sorted[upper] - sorted[lower])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iqr_of_uniform_angles() {
+        let angles: Vec<f64> = (0..360).map(|angle| angle as f64).collect();
+        assert!((circular_iqr(&angles) - 180.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn iqr_of_cluster_across_north() {
+        let angles = [358.0, 359.0, 0.0, 1.0, 2.0, 3.0, 357.0, 0.5];
+        assert!(circular_iqr(&angles) < 3.0);
+        assert!((circular_iqr(&[350.0, 355.0, 5.0, 10.0, 0.0]) - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn percentile_across_north() {
+        let angles = [350.0, 355.0, 5.0, 10.0];
+        assert!((circular_percentile(&angles, 0.0) - 350.0).abs() < 1e-9);
+        assert!((circular_percentile(&angles, 1.0) - 10.0).abs() < 1e-9);
+        assert!((circular_percentile(&angles, 0.25) - 353.75).abs() < 1e-9);
+        assert!(circular_median(&angles).abs() < 1e-9);
+    }
+
+    #[test]
+    fn median_of_evenly_spread_angles() {
+        let median = circular_median(&[0.0, 90.0, 180.0, 270.0]);
+        assert!(median > 90.0 && median < 180.0);
+        // the same input in a different order and shifted past north
+        let median = circular_median(&[360.0, 270.0, -180.0, 90.0]);
+        assert!(median > 90.0 && median < 180.0);
+        assert!((circular_median(&[42.0]) - 42.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_percentile() {
+        assert!(circular_percentile(&[], 0.5).is_nan());
+        assert!(circular_percentile(&[10.0], -0.1).is_nan());
+        assert!(circular_percentile(&[10.0], 1.5).is_nan());
+        assert!(circular_percentile(&[10.0], f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn max_gap() {
+        assert_eq!(max_gap_degrees(&[0.0, 90.0, 180.0, 270.0]), 90.0);
+        assert_eq!(max_gap_degrees(&[0.0, 1.0, 2.0]), 358.0);
+        assert_eq!(max_gap_degrees(&[10.0, 350.0, 180.0]), 170.0);
+        assert_eq!(max_gap_degrees(&[370.0, 10.0]), 360.0);
+        assert_eq!(max_gap_degrees(&[]), 360.0);
+        assert_eq!(max_gap_degrees(&[42.0]), 0.0);
+    }
+
+    #[test]
+    fn too_few_angles() {
+        assert!(circular_iqr(&[]).is_nan());
+        assert!(circular_iqr(&[10.0, 20.0, 30.0]).is_nan());
+    }
+}
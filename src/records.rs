@@ -0,0 +1,115 @@
+use crate::WindDirectionMeasurements;
+use alloc::vec::Vec;
+
+/// A direction in degrees with whatever identifies where it came from, e.g. a station name or
+/// observation ID.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirectionRecord<T> {
+    pub angle_degrees: f64,
+    pub metadata: T,
+}
+
+/// Accumulator that keeps every record with its metadata, to trace statistics back to the
+/// observations behind them. Statistics come from the running sums, see [`Self::measurements`].
+#[derive(Clone, Debug)]
+pub struct DirectionMeasurementsWithRecords<T> {
+    records: Vec<DirectionRecord<T>>,
+    inner: WindDirectionMeasurements,
+}
+
+impl<T> Default for DirectionMeasurementsWithRecords<T> {
+    fn default() -> Self {
+        DirectionMeasurementsWithRecords {
+            records: Vec::new(),
+            inner: WindDirectionMeasurements::new(),
+        }
+    }
+}
+
+impl<T: Clone> DirectionMeasurementsWithRecords<T> {
+    pub fn new() -> Self {
+        DirectionMeasurementsWithRecords::default()
+    }
+
+    pub fn add_record(&mut self, record: DirectionRecord<T>) {
+        self.inner.add_measurement(record.angle_degrees);
+        self.records.push(record);
+    }
+
+    pub fn add_measurement(&mut self, angle_degrees: f64, metadata: T) {
+        self.add_record(DirectionRecord {
+            angle_degrees,
+            metadata,
+        });
+    }
+
+    /// All records in insertion order.
+    pub fn records(&self) -> &[DirectionRecord<T>] {
+        &self.records
+    }
+
+    /// Records matching `predicate`, e.g. those from one station. The statistics of a subset can
+    /// be computed by collecting their angles into a new accumulator.
+    pub fn filter_records(
+        &self,
+        predicate: impl Fn(&DirectionRecord<T>) -> bool,
+    ) -> impl Iterator<Item = &DirectionRecord<T>> {
+        self.records.iter().filter(move |record| predicate(record))
+    }
+
+    /// The running sums of all records, for every statistic of [`WindDirectionMeasurements`].
+    pub fn measurements(&self) -> &WindDirectionMeasurements {
+        &self.inner
+    }
+
+    pub fn average_direction(&self) -> f64 {
+        self.inner.average_direction()
+    }
+
+    pub fn standard_deviation(&self) -> f64 {
+        self.inner.standard_deviation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_metadata() {
+        let mut measurements = DirectionMeasurementsWithRecords::new();
+        measurements.add_measurement(350.0, "Hel");
+        measurements.add_measurement(10.0, "Rozewie");
+        measurements.add_record(DirectionRecord {
+            angle_degrees: 20.0,
+            metadata: "Hel",
+        });
+        let records = measurements.records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1].metadata, "Rozewie");
+        assert_eq!(records[1].angle_degrees, 10.0);
+        let hel: Vec<f64> = measurements
+            .filter_records(|record| record.metadata == "Hel")
+            .map(|record| record.angle_degrees)
+            .collect();
+        assert_eq!(hel, [350.0, 20.0]);
+
+        let expected = WindDirectionMeasurements::from_values(&[350.0, 10.0, 20.0]);
+        assert_eq!(*measurements.measurements(), expected);
+        assert_eq!(
+            measurements.average_direction(),
+            expected.average_direction()
+        );
+        assert_eq!(
+            measurements.standard_deviation(),
+            expected.standard_deviation()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let measurements = DirectionMeasurementsWithRecords::<&str>::new();
+        assert!(measurements.records().is_empty());
+        assert!(measurements.average_direction().is_nan());
+    }
+}
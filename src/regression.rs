@@ -0,0 +1,255 @@
+use crate::{DirectionError, angular_difference_signed};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Least-squares fit of directions `theta_degrees` against a periodic covariate `x` in radians
+/// (e.g. the hour of day scaled to a full turn), returning `(a, b)` such that the direction
+/// predicted for `x` is `atan2(a·cos(x), b·sin(x))`.
+///
+/// The components are fitted separately, `sin θ ≈ a·cos(x)` and `cos θ ≈ b·sin(x)`, so
+/// `a = Σ sin θ cos x / Σ cos² x` and `b = Σ cos θ sin x / Σ sin² x`. Fails when the slices have
+/// different lengths or are empty, a coefficient is `NAN` when its covariate term is zero for
+/// every sample.
+pub fn circular_regression(x: &[f64], theta_degrees: &[f64]) -> Result<(f64, f64), DirectionError> {
+    if x.len() != theta_degrees.len() {
+        return Err(DirectionError::LengthMismatch {
+            left: x.len(),
+            right: theta_degrees.len(),
+        });
+    }
+    if x.is_empty() {
+        return Err(DirectionError::EmptyInput);
+    }
+    let mut sin_theta_cos_x = 0.0;
+    let mut cos_theta_sin_x = 0.0;
+    let mut cos_x_squared = 0.0;
+    let mut sin_x_squared = 0.0;
+    for (&x, &theta) in x.iter().zip(theta_degrees) {
+        let (sin_x, cos_x) = x.sin_cos();
+        let (sin_theta, cos_theta) = theta.to_radians().sin_cos();
+        sin_theta_cos_x += sin_theta * cos_x;
+        cos_theta_sin_x += cos_theta * sin_x;
+        cos_x_squared += cos_x * cos_x;
+        sin_x_squared += sin_x * sin_x;
+    }
+    let coefficient = |numerator: f64, denominator: f64| {
+        if denominator == 0.0 {
+            f64::NAN
+        } else {
+            numerator / denominator
+        }
+    };
+    Ok((
+        coefficient(sin_theta_cos_x, cos_x_squared),
+        coefficient(cos_theta_sin_x, sin_x_squared),
+    ))
+}
+
+/// Rate of change of the direction in degrees per unit of time, positive for a clockwise drift
+/// (veering) and negative for a counterclockwise one (backing). `data` holds
+/// `(time, angle_degrees)` pairs in time order.
+///
+/// The angles are unwrapped into a continuous trace by taking each step the short way around,
+/// so consecutive samples must be less than 180° apart, and the slope of that trace against time
+/// is fitted by least squares. `NAN` for fewer than 2 points or when all times are equal.
+pub fn circular_trend(data: &[(f64, f64)]) -> f64 {
+    if data.len() < 2 {
+        return f64::NAN;
+    }
+    let unwrapped = || {
+        data.iter()
+            .scan(None, |previous: &mut Option<(f64, f64)>, &(time, angle)| {
+                let trace = match *previous {
+                    Some((previous_angle, previous_trace)) => {
+                        previous_trace + angular_difference_signed(angle, previous_angle)
+                    }
+                    None => angle,
+                };
+                *previous = Some((angle, trace));
+                Some((time, trace))
+            })
+    };
+    let n = data.len() as f64;
+    let (sum_time, sum_trace) = unwrapped()
+        .fold((0.0, 0.0), |(sum_time, sum_trace), (time, trace)| {
+            (sum_time + time, sum_trace + trace)
+        });
+    let (mean_time, mean_trace) = (sum_time / n, sum_trace / n);
+    let (covariance, variance) =
+        unwrapped().fold((0.0, 0.0), |(covariance, variance), (time, trace)| {
+            let dt = time - mean_time;
+            (covariance + dt * (trace - mean_trace), variance + dt * dt)
+        });
+    if variance == 0.0 {
+        return f64::NAN;
+    }
+    covariance / variance
+}
+
+/// Circular-linear correlation coefficient in [0, 1] between directions `angles` in degrees and
+/// a linear variable `x`, e.g. temperature (Mardia 1976):
+/// `√((r_xc² + r_xs² - 2 r_xc r_xs r_cs) / (1 - r_cs²))`, with `r_xc`, `r_xs` and `r_cs` the
+/// Pearson correlations of `x` with `cos θ`, of `x` with `sin θ` and of `cos θ` with `sin θ`.
+///
+/// Fails when the slices have different lengths or are empty. `NAN` when `x`, `cos θ` or `sin θ`
+/// is constant, or `cos θ` and `sin θ` are perfectly correlated (all angles on one line through
+/// the origin).
+pub fn circular_linear_correlation(angles: &[f64], x: &[f64]) -> Result<f64, DirectionError> {
+    if angles.len() != x.len() {
+        return Err(DirectionError::LengthMismatch {
+            left: angles.len(),
+            right: x.len(),
+        });
+    }
+    if angles.is_empty() {
+        return Err(DirectionError::EmptyInput);
+    }
+    let n = angles.len() as f64;
+    let (sum_x, sum_cos, sum_sin) = angles.iter().zip(x).fold(
+        (0.0, 0.0, 0.0),
+        |(sum_x, sum_cos, sum_sin), (&angle, &x)| {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            (sum_x + x, sum_cos + cos, sum_sin + sin)
+        },
+    );
+    let (mean_x, mean_cos, mean_sin) = (sum_x / n, sum_cos / n, sum_sin / n);
+    // centered sums of squares and cross products
+    let mut s = [0.0; 6];
+    for (&angle, &x) in angles.iter().zip(x) {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let (dx, dc, ds) = (x - mean_x, cos - mean_cos, sin - mean_sin);
+        s[0] += dx * dx;
+        s[1] += dc * dc;
+        s[2] += ds * ds;
+        s[3] += dx * dc;
+        s[4] += dx * ds;
+        s[5] += dc * ds;
+    }
+    let [xx, cc, ss, xc, xs, cs] = s;
+    let r_xc = xc / (xx * cc).sqrt();
+    let r_xs = xs / (xx * ss).sqrt();
+    let r_cs = cs / (cc * ss).sqrt();
+    let r_squared = (r_xc * r_xc + r_xs * r_xs - 2.0 * r_xc * r_xs * r_cs) / (1.0 - r_cs * r_cs);
+    Ok(r_squared.clamp(0.0, 1.0).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::angular_distance;
+
+    fn predict((a, b): (f64, f64), x: f64) -> f64 {
+        crate::normalize_angle((a * x.cos()).atan2(b * x.sin()).to_degrees())
+    }
+
+    fn covariate() -> impl Iterator<Item = f64> {
+        (0..24).map(|hour| hour as f64 / 24.0 * core::f64::consts::TAU)
+    }
+
+    #[test]
+    fn recovers_known_coefficients() {
+        for coefficients in [(1.0, 1.0), (-1.0, 1.0), (1.0, -1.0)] {
+            let x: Vec<f64> = covariate().collect();
+            let theta: Vec<f64> = x.iter().map(|&x| predict(coefficients, x)).collect();
+            let (a, b) = circular_regression(&x, &theta).unwrap();
+            assert!((a - coefficients.0).abs() < 1e-12);
+            assert!((b - coefficients.1).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn predictions_follow_diurnal_cycle() {
+        // sea breeze turning around with the hour of day, plus alternating noise
+        let x: Vec<f64> = covariate().collect();
+        let theta: Vec<f64> = x
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| 90.0 - x.to_degrees() + if i % 2 == 0 { 5.0 } else { -5.0 })
+            .collect();
+        let coefficients = circular_regression(&x, &theta).unwrap();
+        for &x in &x {
+            let expected = 90.0 - x.to_degrees();
+            assert!(angular_distance(predict(coefficients, x), expected) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn recovers_veering_trend() {
+        // one degree per step through north, with alternating noise
+        let data: Vec<(f64, f64)> = (0..50)
+            .map(|step| {
+                let noise = if step % 2 == 0 { 3.0 } else { -3.0 };
+                (
+                    step as f64,
+                    crate::normalize_angle(330.0 + step as f64 + noise),
+                )
+            })
+            .collect();
+        assert!((circular_trend(&data) - 1.0).abs() < 0.01);
+        let backing: Vec<(f64, f64)> = (0..50)
+            .map(|step| {
+                (
+                    step as f64 * 0.5,
+                    crate::normalize_angle(20.0 - step as f64 * 5.0),
+                )
+            })
+            .collect();
+        assert!((circular_trend(&backing) + 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn undefined_trend() {
+        assert!(circular_trend(&[]).is_nan());
+        assert!(circular_trend(&[(0.0, 10.0)]).is_nan());
+        assert!(circular_trend(&[(1.0, 10.0), (1.0, 20.0)]).is_nan());
+        assert_eq!(circular_trend(&[(0.0, 10.0), (1.0, 10.0)]), 0.0);
+    }
+
+    #[test]
+    fn linear_association() {
+        let angles: Vec<f64> = (0..36).map(|i| i as f64 * 10.0).collect();
+        let sine: Vec<f64> = angles
+            .iter()
+            .map(|angle| 3.0 * angle.to_radians().sin() + 15.0)
+            .collect();
+        assert!((circular_linear_correlation(&angles, &sine).unwrap() - 1.0).abs() < 1e-9);
+        let mixed: Vec<f64> = angles
+            .iter()
+            .map(|angle| (angle - 30.0).to_radians().cos())
+            .collect();
+        assert!((circular_linear_correlation(&angles, &mixed).unwrap() - 1.0).abs() < 1e-9);
+        // a variable that alternates regardless of direction
+        let unrelated: Vec<f64> = (0..36)
+            .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+            .collect();
+        assert!(circular_linear_correlation(&angles, &unrelated).unwrap() < 0.1);
+    }
+
+    #[test]
+    fn undefined_linear_association() {
+        assert_eq!(
+            circular_linear_correlation(&[10.0], &[1.0, 2.0]),
+            Err(DirectionError::LengthMismatch { left: 1, right: 2 })
+        );
+        assert_eq!(
+            circular_linear_correlation(&[], &[]),
+            Err(DirectionError::EmptyInput)
+        );
+        let constant = circular_linear_correlation(&[10.0, 20.0, 30.0], &[1.0, 1.0, 1.0]);
+        assert!(constant.unwrap().is_nan());
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            circular_regression(&[1.0, 2.0], &[10.0]),
+            Err(DirectionError::LengthMismatch { left: 2, right: 1 })
+        );
+        assert_eq!(
+            circular_regression(&[], &[]),
+            Err(DirectionError::EmptyInput)
+        );
+        let (a, b) = circular_regression(&[0.0], &[90.0]).unwrap();
+        assert!((a - 1.0).abs() < 1e-12 && b.is_nan());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::WindDirectionMeasurements;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Statistics over the last `capacity` measurements, evicting the oldest one when full.
+#[derive(Clone, Debug)]
+pub struct RollingDirectionMeasurements {
+    capacity: usize,
+    buffer: VecDeque<f64>,
+    inner: WindDirectionMeasurements,
+}
+
+impl RollingDirectionMeasurements {
+    pub fn new(capacity: usize) -> Self {
+        RollingDirectionMeasurements {
+            capacity,
+            buffer: VecDeque::with_capacity(capacity),
+            inner: WindDirectionMeasurements::new(),
+        }
+    }
+
+    pub fn add_measurement(&mut self, angle_degrees: f64) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.buffer.len() == self.capacity
+            && let Some(evicted) = self.buffer.pop_front()
+        {
+            self.inner.remove_measurement(evicted);
+        }
+        self.buffer.push_back(angle_degrees);
+        self.inner.add_measurement(angle_degrees);
+    }
+
+    pub fn average_direction(&self) -> f64 {
+        self.inner.average_direction()
+    }
+
+    pub fn standard_deviation(&self) -> f64 {
+        self.inner.standard_deviation()
+    }
+}
+
+/// Circular mean of the centered `window` around each angle, same length as `angles`. Element `i`
+/// averages `angles[i - (window - 1) / 2..=i + window / 2]` and is `NAN` where that range does
+/// not fit inside the slice (so everywhere when `window` is 0 or longer than the input). The
+/// window slides by adding and removing single measurements, O(n) regardless of its size.
+pub fn circular_moving_average(angles: &[f64], window: usize) -> Vec<f64> {
+    let mut averages = alloc::vec![f64::NAN; angles.len()];
+    if window == 0 || window > angles.len() {
+        return averages;
+    }
+    let before = (window - 1) / 2;
+    let mut measurements = WindDirectionMeasurements::from_values(&angles[..window]);
+    averages[before] = measurements.average_direction();
+    for end in window..angles.len() {
+        measurements.remove_measurement(angles[end - window]);
+        measurements.add_measurement(angles[end]);
+        averages[end - window + 1 + before] = measurements.average_direction();
+    }
+    averages
+}
+
+/// Convolution of a direction series with an FIR `kernel`, applied to the unit vectors of the
+/// angles rather than to the degrees, so filtering across north does not jump. Output element `i`
+/// is the direction of `Σ kernel[j] · v[i + kernel.len() / 2 - j]`, `v` the unit vectors of
+/// `signal`, aligned like [`circular_moving_average`] so a box kernel gives the same result. It is
+/// `NAN` where the kernel does not fit inside the signal.
+pub fn circular_direction_convolution(signal: &[f64], kernel: &[f64]) -> Vec<f64> {
+    let mut directions = alloc::vec![f64::NAN; signal.len()];
+    if kernel.is_empty() || kernel.len() > signal.len() {
+        return directions;
+    }
+    let vectors: Vec<(f64, f64)> = signal
+        .iter()
+        .map(|angle| angle.to_radians().sin_cos())
+        .collect();
+    let before = (kernel.len() - 1) / 2;
+    for (start, window) in vectors.windows(kernel.len()).enumerate() {
+        let (sum_sin, sum_cos) = window.iter().rev().zip(kernel).fold(
+            (0.0, 0.0),
+            |(sum_sin, sum_cos), (&(sin, cos), &weight)| {
+                (sum_sin + weight * sin, sum_cos + weight * cos)
+            },
+        );
+        directions[start + before] = crate::normalize_angle(sum_sin.atan2(sum_cos).to_degrees());
+    }
+    directions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_oldest_measurement() {
+        let mut rolling = RollingDirectionMeasurements::new(3);
+        for angle in [0.0, 90.0, 180.0, 270.0] {
+            rolling.add_measurement(angle);
+        }
+        let expected = WindDirectionMeasurements::from_values(&[90.0, 180.0, 270.0]);
+        assert!((rolling.average_direction() - expected.average_direction()).abs() < 1e-9);
+        assert!((rolling.standard_deviation() - expected.standard_deviation()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn moving_average() {
+        let angles = [350.0, 10.0, 20.0, 40.0, 330.0, 0.0, 90.0];
+        let averages = circular_moving_average(&angles, 3);
+        assert_eq!(averages.len(), angles.len());
+        assert!(averages[0].is_nan() && averages[6].is_nan());
+        for center in 1..6 {
+            let expected = WindDirectionMeasurements::from_values(&angles[center - 1..=center + 1]);
+            assert!((averages[center] - expected.average_direction()).abs() < 1e-9);
+        }
+
+        let even = circular_moving_average(&angles, 4);
+        assert!(even[0].is_nan() && even[5].is_nan() && even[6].is_nan());
+        let expected = WindDirectionMeasurements::from_values(&angles[..4]);
+        assert!((even[1] - expected.average_direction()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn moving_average_edge_cases() {
+        assert!(
+            circular_moving_average(&[10.0, 20.0], 0)
+                .iter()
+                .all(|a| a.is_nan())
+        );
+        assert!(
+            circular_moving_average(&[10.0, 20.0], 3)
+                .iter()
+                .all(|a| a.is_nan())
+        );
+        assert!(circular_moving_average(&[], 1).is_empty());
+        assert_eq!(circular_moving_average(&[10.0, 20.0], 1).len(), 2);
+        assert!((circular_moving_average(&[10.0, 20.0], 1)[1] - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn box_kernel_matches_moving_average() {
+        let angles = [350.0, 10.0, 20.0, 40.0, 330.0, 0.0, 90.0, 100.0];
+        for window in [1, 3, 4] {
+            let convolved = circular_direction_convolution(&angles, &alloc::vec![0.5; window]);
+            let averaged = circular_moving_average(&angles, window);
+            assert_eq!(convolved.len(), angles.len());
+            for (convolved, averaged) in convolved.iter().zip(&averaged) {
+                assert_eq!(convolved.is_nan(), averaged.is_nan());
+                if !averaged.is_nan() {
+                    assert!(crate::angular_distance(*convolved, *averaged) < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn asymmetric_kernel() {
+        // a kernel that only keeps the next sample shifts the series by one
+        let angles = [10.0, 20.0, 30.0, 40.0];
+        let shifted = circular_direction_convolution(&angles, &[1.0, 0.0]);
+        assert!((shifted[0] - 20.0).abs() < 1e-9);
+        assert!((shifted[2] - 40.0).abs() < 1e-9);
+        assert!(shifted[3].is_nan());
+        assert!(
+            circular_direction_convolution(&angles, &[])
+                .iter()
+                .all(|d| d.is_nan())
+        );
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut rolling = RollingDirectionMeasurements::new(0);
+        rolling.add_measurement(90.0);
+        assert!(rolling.average_direction().is_nan());
+        assert!(rolling.standard_deviation().is_nan());
+    }
+}
@@ -0,0 +1,170 @@
+// AVX2 batch accumulation, only compiled when the target enables AVX2 (e.g. with
+// `-C target-feature=+avx2` or `-C target-cpu=native`).
+//
+// There are no sine/cosine instructions, so they are evaluated with the Cephes `sin`/`cos`
+// range reduction and polynomials, which are accurate to about 1 ulp but not bit-identical to the
+// platform libm.
+
+use core::arch::x86_64::*;
+
+const FOUR_OVER_PI: f64 = 1.273_239_544_735_162_7;
+// pi/4 split into three parts for the Cody-Waite range reduction
+const DP1: f64 = 7.853_981_256_484_985e-1;
+const DP2: f64 = 3.774_894_707_930_798e-8;
+const DP3: f64 = 2.695_151_429_079_06e-15;
+const SIN_COEFFICIENTS: [f64; 6] = [
+    1.589_623_015_765_465_7e-10,
+    -2.505_074_776_285_781e-8,
+    2.755_731_362_138_572_4e-6,
+    -1.984_126_982_958_954e-4,
+    8.333_333_333_322_118e-3,
+    -1.666_666_666_666_663e-1,
+];
+const COS_COEFFICIENTS: [f64; 6] = [
+    -1.135_853_652_138_768_2e-11,
+    2.087_570_084_197_473e-9,
+    -2.755_731_417_929_674e-7,
+    2.480_158_728_885_170_4e-5,
+    -1.388_888_888_887_305_6e-3,
+    4.166_666_666_666_659e-2,
+];
+// beyond this the reduction loses precision, such chunks use the scalar path
+const MAX_REDUCIBLE_RAD: f64 = 1e8;
+
+// Running sums of sin/cos of the angles and of the doubled angles.
+#[derive(Default)]
+pub(crate) struct BatchSums {
+    pub(crate) count: u64,
+    pub(crate) sin: f64,
+    pub(crate) cos: f64,
+    pub(crate) sin_2: f64,
+    pub(crate) cos_2: f64,
+}
+
+#[target_feature(enable = "avx2")]
+fn polynomial(x: __m256d, coefficients: &[f64; 6]) -> __m256d {
+    let mut result = _mm256_set1_pd(coefficients[0]);
+    for &coefficient in &coefficients[1..] {
+        result = _mm256_add_pd(_mm256_mul_pd(result, x), _mm256_set1_pd(coefficient));
+    }
+    result
+}
+
+#[target_feature(enable = "avx2")]
+fn sin_cos(x: __m256d) -> (__m256d, __m256d) {
+    let sign_mask = _mm256_set1_pd(-0.0);
+    let input_sign = _mm256_and_pd(x, sign_mask);
+    let abs_x = _mm256_andnot_pd(sign_mask, x);
+
+    // octant, rounded up to an even number so that z is in [-pi/4, pi/4]
+    let octant = _mm256_cvttpd_epi32(_mm256_floor_pd(_mm256_mul_pd(
+        abs_x,
+        _mm256_set1_pd(FOUR_OVER_PI),
+    )));
+    let octant = _mm_and_si128(_mm_add_epi32(octant, _mm_set1_epi32(1)), _mm_set1_epi32(!1));
+    let y = _mm256_cvtepi32_pd(octant);
+    let quadrant = _mm256_cvtepi32_epi64(_mm_and_si128(
+        _mm_srli_epi32::<1>(octant),
+        _mm_set1_epi32(3),
+    ));
+
+    let z = _mm256_sub_pd(abs_x, _mm256_mul_pd(y, _mm256_set1_pd(DP1)));
+    let z = _mm256_sub_pd(z, _mm256_mul_pd(y, _mm256_set1_pd(DP2)));
+    let z = _mm256_sub_pd(z, _mm256_mul_pd(y, _mm256_set1_pd(DP3)));
+    let zz = _mm256_mul_pd(z, z);
+
+    let sin_polynomial = _mm256_add_pd(
+        z,
+        _mm256_mul_pd(_mm256_mul_pd(z, zz), polynomial(zz, &SIN_COEFFICIENTS)),
+    );
+    let cos_polynomial = _mm256_add_pd(
+        _mm256_sub_pd(_mm256_set1_pd(1.0), _mm256_mul_pd(zz, _mm256_set1_pd(0.5))),
+        _mm256_mul_pd(_mm256_mul_pd(zz, zz), polynomial(zz, &COS_COEFFICIENTS)),
+    );
+
+    // odd quadrants swap the polynomials
+    let one = _mm256_set1_epi64x(1);
+    let swap = _mm256_castsi256_pd(_mm256_cmpeq_epi64(_mm256_and_si256(quadrant, one), one));
+    let sin = _mm256_blendv_pd(sin_polynomial, cos_polynomial, swap);
+    let cos = _mm256_blendv_pd(cos_polynomial, sin_polynomial, swap);
+
+    // sine is negative in quadrants 2 and 3 (and for negative input), cosine in 1 and 2
+    let two = _mm256_set1_epi64x(2);
+    let sin_sign = _mm256_castsi256_pd(_mm256_slli_epi64::<62>(_mm256_and_si256(quadrant, two)));
+    let cos_sign = _mm256_castsi256_pd(_mm256_slli_epi64::<62>(_mm256_and_si256(
+        _mm256_add_epi64(quadrant, one),
+        two,
+    )));
+    (
+        _mm256_xor_pd(sin, _mm256_xor_pd(sin_sign, input_sign)),
+        _mm256_xor_pd(cos, cos_sign),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+fn horizontal_sum(x: __m256d) -> f64 {
+    let mut lanes = [0.0; 4];
+    // SAFETY: `lanes` has room for the four unaligned lanes.
+    unsafe { _mm256_storeu_pd(lanes.as_mut_ptr(), x) };
+    lanes.iter().sum()
+}
+
+// Accumulates chunks of four angles, returns the sums and the unprocessed remainder.
+#[target_feature(enable = "avx2")]
+pub(crate) fn accumulate(angles_degrees: &[f64]) -> (BatchSums, &[f64]) {
+    let mut sums = BatchSums::default();
+    let mut sin = _mm256_setzero_pd();
+    let mut cos = _mm256_setzero_pd();
+    let mut sin_2 = _mm256_setzero_pd();
+    let mut cos_2 = _mm256_setzero_pd();
+    let to_radians = _mm256_set1_pd(core::f64::consts::PI / 180.0);
+    let max_reducible = _mm256_set1_pd(MAX_REDUCIBLE_RAD / 2.0);
+    let sign_mask = _mm256_set1_pd(-0.0);
+
+    let chunks = angles_degrees.chunks_exact(4);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        // SAFETY: the chunk holds exactly four f64 values, the load is unaligned.
+        let angle_rad = _mm256_mul_pd(unsafe { _mm256_loadu_pd(chunk.as_ptr()) }, to_radians);
+        let abs_angle = _mm256_andnot_pd(sign_mask, angle_rad);
+        if _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_GT_OQ>(abs_angle, max_reducible)) != 0 {
+            for &angle in chunk {
+                let angle_rad = angle.to_radians();
+                sums.sin += angle_rad.sin();
+                sums.cos += angle_rad.cos();
+                sums.sin_2 += (angle_rad + angle_rad).sin();
+                sums.cos_2 += (angle_rad + angle_rad).cos();
+            }
+        } else {
+            let (chunk_sin, chunk_cos) = sin_cos(angle_rad);
+            let (chunk_sin_2, chunk_cos_2) = sin_cos(_mm256_add_pd(angle_rad, angle_rad));
+            sin = _mm256_add_pd(sin, chunk_sin);
+            cos = _mm256_add_pd(cos, chunk_cos);
+            sin_2 = _mm256_add_pd(sin_2, chunk_sin_2);
+            cos_2 = _mm256_add_pd(cos_2, chunk_cos_2);
+        }
+        sums.count += 4;
+    }
+    sums.sin += horizontal_sum(sin);
+    sums.cos += horizontal_sum(cos);
+    sums.sin_2 += horizontal_sum(sin_2);
+    sums.cos_2 += horizontal_sum(cos_2);
+    (sums, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_scalar_sin_cos() {
+        for i in -2000..2000 {
+            let angle_rad = (i as f64 * 0.37).to_radians() * 7.0;
+            // SAFETY: this module is only compiled when AVX2 is enabled for the target.
+            let (sin, cos) = unsafe { sin_cos(_mm256_set1_pd(angle_rad)) };
+            let (sin, cos) = unsafe { (horizontal_sum(sin), horizontal_sum(cos)) };
+            assert!((sin / 4.0 - angle_rad.sin()).abs() < 1e-15);
+            assert!((cos / 4.0 - angle_rad.cos()).abs() < 1e-15);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{DirectionError, WindDirectionMeasurements};
+use num_traits::Float;
+
+/// Plain copy of the accumulator state for checkpointing, tagged with the layout version so that
+/// snapshots written by an incompatible release are rejected by
+/// [`WindDirectionMeasurements::restore`] instead of being misread.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DirectionMeasurementsSnapshot<F = f64> {
+    pub version: u32,
+    pub count: u64,
+    pub sum_weights: F,
+    pub sum_sin_rad: F,
+    pub sum_cos_rad: F,
+    pub sum_sin_2rad: F,
+    pub sum_cos_2rad: F,
+}
+
+impl<F> DirectionMeasurementsSnapshot<F> {
+    /// Version written by [`WindDirectionMeasurements::snapshot`].
+    pub const CURRENT_VERSION: u32 = 1;
+}
+
+impl<F: Float> WindDirectionMeasurements<F> {
+    pub fn snapshot(&self) -> DirectionMeasurementsSnapshot<F> {
+        DirectionMeasurementsSnapshot {
+            version: DirectionMeasurementsSnapshot::<F>::CURRENT_VERSION,
+            count: self.count,
+            sum_weights: self.sum_weights,
+            sum_sin_rad: self.sum_sin_rad,
+            sum_cos_rad: self.sum_cos_rad,
+            sum_sin_2rad: self.sum_sin_2rad,
+            sum_cos_2rad: self.sum_cos_2rad,
+        }
+    }
+
+    /// Rebuilds the accumulator from [`Self::snapshot`], failing when the snapshot has a different
+    /// version.
+    pub fn restore(snapshot: &DirectionMeasurementsSnapshot<F>) -> Result<Self, DirectionError> {
+        if snapshot.version != DirectionMeasurementsSnapshot::<F>::CURRENT_VERSION {
+            return Err(DirectionError::IncompatibleSnapshot {
+                version: snapshot.version,
+            });
+        }
+        Ok(WindDirectionMeasurements {
+            count: snapshot.count,
+            sum_weights: snapshot.sum_weights,
+            sum_sin_rad: snapshot.sum_sin_rad,
+            sum_cos_rad: snapshot.sum_cos_rad,
+            sum_sin_2rad: snapshot.sum_sin_2rad,
+            sum_cos_2rad: snapshot.sum_cos_2rad,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore_and_continue() {
+        let (first, second) = [350.0, 10.0, 20.0, 200.0, 275.0, 5.0, 45.0, 310.0].split_at(3);
+        let snapshot = WindDirectionMeasurements::from_values(first).snapshot();
+        let mut restored = WindDirectionMeasurements::restore(&snapshot).unwrap();
+        restored.extend(second.iter().copied());
+        let reference: WindDirectionMeasurements = first.iter().chain(second).copied().collect();
+        assert_eq!(restored, reference);
+        assert_eq!(restored.average_direction(), reference.average_direction());
+        assert_eq!(restored.circular_skewness(), reference.circular_skewness());
+    }
+
+    #[test]
+    fn rejects_other_version() {
+        let mut snapshot = WindDirectionMeasurements::from_values(&[90.0]).snapshot();
+        snapshot.version = 2;
+        assert_eq!(
+            WindDirectionMeasurements::restore(&snapshot),
+            Err(DirectionError::IncompatibleSnapshot { version: 2 })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let snapshot = WindDirectionMeasurements::from_values(&[10.0, 20.0]).snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: DirectionMeasurementsSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.version, snapshot.version);
+        assert_eq!(restored.count, snapshot.count);
+        assert!((restored.sum_sin_rad - snapshot.sum_sin_rad).abs() < 1e-12);
+        assert!((restored.sum_cos_rad - snapshot.sum_cos_rad).abs() < 1e-12);
+    }
+}
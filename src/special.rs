@@ -0,0 +1,364 @@
+// Numerical helpers shared by the statistical tests, computed in f64 regardless of the
+// accumulator's float type.
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// `f64::rem_euclid` is not available without `std`.
+pub(crate) fn rem_euclid(value: f64, modulus: f64) -> f64 {
+    let remainder = value % modulus;
+    if remainder < 0.0 {
+        remainder + modulus
+    } else {
+        remainder
+    }
+}
+
+// Inverse of the standard normal CDF, Acklam's rational approximation (relative error < 1.2e-9).
+pub(crate) fn standard_normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    if p <= 0.0 || p >= 1.0 {
+        return f64::NAN;
+    }
+    if p < P_LOW {
+        let q = f64::sqrt(-2.0 * p.ln());
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -standard_normal_quantile(1.0 - p)
+    }
+}
+
+// Complementary error function, Numerical Recipes `erfcc` (fractional error < 1.2e-7).
+pub(crate) fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let r = t * f64::exp(
+        -z * z - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))),
+    );
+    if x >= 0.0 { r } else { 2.0 - r }
+}
+
+pub(crate) fn standard_normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / core::f64::consts::SQRT_2)
+}
+
+// Natural logarithm of the gamma function for x >= 0.5, Lanczos approximation (g = 7, n = 9).
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let x = x - 1.0;
+    let mut sum = COEFFICIENTS[0];
+    for (i, &coefficient) in COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += coefficient / (x + i as f64);
+    }
+    let t = x + 7.5;
+    0.5 * f64::ln(core::f64::consts::TAU) + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+// Continued fraction of the incomplete beta function, modified Lentz method (Numerical Recipes
+// `betacf`).
+fn incomplete_beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    for m in 1..300 {
+        let m = m as f64;
+        let numerator = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        for (i, numerator) in [
+            numerator,
+            -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0)),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            h *= d * c;
+            if i == 1 && (d * c - 1.0).abs() < 1e-15 {
+                return h;
+            }
+        }
+    }
+    h
+}
+
+// Regularized incomplete beta function I_x(a, b) for x in [0, 1].
+pub(crate) fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        f64::exp(ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln());
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * incomplete_beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * incomplete_beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+// Upper tail probability P(X > f) of the F distribution with (d1, d2) degrees of freedom.
+pub(crate) fn f_distribution_sf(f: f64, d1: f64, d2: f64) -> f64 {
+    if f <= 0.0 {
+        return 1.0;
+    }
+    regularized_incomplete_beta(d2 / 2.0, d1 / 2.0, d2 / (d2 + d1 * f))
+}
+
+// Approximate maximum likelihood estimate of the von Mises concentration from the mean resultant
+// length, Fisher (1993) eq. 4.40.
+pub(crate) fn approximate_kappa(mean_resultant_length: f64) -> f64 {
+    let r = mean_resultant_length;
+    if r < 0.53 {
+        2.0 * r + r.powi(3) + 5.0 * r.powi(5) / 6.0
+    } else if r < 0.85 {
+        -0.4 + 1.39 * r + 0.43 / (1.0 - r)
+    } else {
+        1.0 / (r.powi(3) - 4.0 * r.powi(2) + 3.0 * r)
+    }
+}
+
+// Modified Bessel functions of order 0 and 1, Numerical Recipes polynomial approximations with a
+// relative error around 1e-7. For x >= 3.75 the polynomials give `I(x) √x / eˣ`, so that callers
+// can cancel or take the logarithm of the exponential factor instead of overflowing.
+fn bessel_i0_i1_small(x: f64) -> (f64, f64) {
+    let y = (x / 3.75).powi(2);
+    let i0 = 1.0
+        + y * (3.515_622_9
+            + y * (3.089_942_4
+                + y * (1.206_749_2 + y * (0.265_973_2 + y * (0.036_076_8 + y * 0.004_581_3)))));
+    let i1 = x
+        * (0.5
+            + y * (0.878_905_94
+                + y * (0.514_988_69
+                    + y * (0.150_849_34
+                        + y * (0.026_587_33 + y * (0.003_015_32 + y * 0.000_324_11))))));
+    (i0, i1)
+}
+
+fn bessel_i0_i1_large_scaled(x: f64) -> (f64, f64) {
+    let y = 3.75 / x;
+    let scaled_i0 = 0.398_942_28
+        + y * (0.013_285_92
+            + y * (0.002_253_19
+                + y * (-0.001_575_65
+                    + y * (0.009_162_81
+                        + y * (-0.020_577_06
+                            + y * (0.026_355_37 + y * (-0.016_476_33 + y * 0.003_923_77)))))));
+    let scaled_i1 = 0.398_942_28
+        + y * (-0.039_880_24
+            + y * (-0.003_620_18
+                + y * (0.001_638_01
+                    + y * (-0.010_315_55
+                        + y * (0.022_829_67
+                            + y * (-0.028_953_12 + y * (0.017_876_54 - y * 0.004_200_59)))))));
+    (scaled_i0, scaled_i1)
+}
+
+// Ratio I1(x) / I0(x) for x >= 0, the mean resultant length of a von Mises distribution with
+// concentration x. Uses the asymptotic expansion for very large x.
+pub(crate) fn bessel_i1_over_i0(x: f64) -> f64 {
+    if x < 3.75 {
+        let (i0, i1) = bessel_i0_i1_small(x);
+        i1 / i0
+    } else if x < 50.0 {
+        let (scaled_i0, scaled_i1) = bessel_i0_i1_large_scaled(x);
+        scaled_i1 / scaled_i0
+    } else {
+        1.0 - 1.0 / (2.0 * x) - 1.0 / (8.0 * x.powi(2)) - 1.0 / (8.0 * x.powi(3))
+    }
+}
+
+// Natural logarithm of I0(x) for x >= 0, finite for large x where I0 itself overflows.
+pub(crate) fn ln_bessel_i0(x: f64) -> f64 {
+    if x < 3.75 {
+        bessel_i0_i1_small(x).0.ln()
+    } else {
+        x - 0.5 * x.ln() + bessel_i0_i1_large_scaled(x).0.ln()
+    }
+}
+
+// Maximum likelihood estimate of the von Mises concentration, solves I1(k) / I0(k) = r with
+// Newton-Raphson starting from the closed-form approximation.
+pub(crate) fn von_mises_kappa(mean_resultant_length: f64) -> f64 {
+    let r = mean_resultant_length;
+    if r.is_nan() {
+        return f64::NAN;
+    }
+    if r <= 0.0 {
+        return 0.0;
+    }
+    if r >= 1.0 {
+        return f64::INFINITY;
+    }
+    let mut kappa = approximate_kappa(r);
+    for _ in 0..100 {
+        let a = bessel_i1_over_i0(kappa);
+        let derivative = 1.0 - a / kappa - a * a;
+        let mut next = kappa - (a - r) / derivative;
+        if next <= 0.0 || next.is_nan() {
+            next = kappa / 2.0;
+        }
+        let converged = (next - kappa).abs() <= 1e-12 * kappa;
+        kappa = next;
+        if converged {
+            break;
+        }
+    }
+    kappa
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn euclidean_remainder() {
+        assert_eq!(rem_euclid(370.0, 360.0), 10.0);
+        assert_eq!(rem_euclid(-10.0, 360.0), 350.0);
+        assert_eq!(rem_euclid(720.0, 360.0), 0.0);
+    }
+
+    #[test]
+    fn normal_quantiles() {
+        assert!(standard_normal_quantile(0.5).abs() < 1e-9);
+        assert!((standard_normal_quantile(0.975) - 1.959964).abs() < 1e-6);
+        assert!((standard_normal_quantile(0.005) + 2.575829).abs() < 1e-6);
+        assert!(standard_normal_quantile(0.0).is_nan());
+    }
+
+    #[test]
+    fn normal_cdf() {
+        assert!((standard_normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((standard_normal_cdf(1.959964) - 0.975).abs() < 1e-6);
+        assert!((standard_normal_cdf(-1.959964) - 0.025).abs() < 1e-6);
+    }
+
+    #[test]
+    fn bessel_ratio() {
+        assert_eq!(bessel_i1_over_i0(0.0), 0.0);
+        // I1(1) / I0(1) and I1(10) / I0(10)
+        assert!((bessel_i1_over_i0(1.0) - 0.446_390_6).abs() < 1e-6);
+        assert!((bessel_i1_over_i0(10.0) - 0.948_599_8).abs() < 1e-6);
+        assert!((bessel_i1_over_i0(49.999) - bessel_i1_over_i0(50.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn log_bessel_i0() {
+        assert_eq!(ln_bessel_i0(0.0), 0.0);
+        // I0(1) = 1.2660659 and I0(10) = 2815.7166
+        assert!((ln_bessel_i0(1.0) - 1.266_065_9f64.ln()).abs() < 1e-6);
+        assert!((ln_bessel_i0(10.0) - 2_815.716_6f64.ln()).abs() < 1e-6);
+        assert!(ln_bessel_i0(1e4).is_finite());
+    }
+
+    #[test]
+    fn kappa_maximum_likelihood() {
+        assert_eq!(von_mises_kappa(0.0), 0.0);
+        assert_eq!(von_mises_kappa(1.0), f64::INFINITY);
+        for r in [0.01, 0.3, 0.53, 0.7, 0.85, 0.95, 0.999] {
+            let kappa = von_mises_kappa(r);
+            assert!((bessel_i1_over_i0(kappa) - r).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn gamma_function() {
+        assert!(ln_gamma(1.0).abs() < 1e-12);
+        assert!(ln_gamma(2.0).abs() < 1e-12);
+        assert!((ln_gamma(0.5) - core::f64::consts::PI.sqrt().ln()).abs() < 1e-12);
+        assert!((ln_gamma(10.0) - 362_880.0f64.ln()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn incomplete_beta() {
+        assert_eq!(regularized_incomplete_beta(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(regularized_incomplete_beta(2.0, 3.0, 1.0), 1.0);
+        // I_x(1, 1) = x and I_x(2, 1) = x²
+        assert!((regularized_incomplete_beta(1.0, 1.0, 0.3) - 0.3).abs() < 1e-12);
+        assert!((regularized_incomplete_beta(2.0, 1.0, 0.3) - 0.09).abs() < 1e-12);
+        assert!((regularized_incomplete_beta(0.5, 0.5, 0.5) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn f_distribution_tail() {
+        assert_eq!(f_distribution_sf(0.0, 1.0, 20.0), 1.0);
+        // F(1, 20) = t² with 20 degrees of freedom, two-sided p of t = 2 is 0.059266
+        assert!((f_distribution_sf(4.0, 1.0, 20.0) - 0.059_266).abs() < 1e-6);
+        // upper 5% point of F(2, 10)
+        assert!((f_distribution_sf(4.102_821, 2.0, 10.0) - 0.05).abs() < 1e-6);
+    }
+
+    #[test]
+    fn kappa_approximation() {
+        assert_eq!(approximate_kappa(0.0), 0.0);
+        assert!(approximate_kappa(0.5) < approximate_kappa(0.7));
+        assert!(approximate_kappa(0.7) < approximate_kappa(0.99));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{DirectionError, WindDirectionMeasurements};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Vector-averaged wind direction in degrees, each angle weighted by its wind speed:
+/// `atan2(Σ speed·sin(angle), Σ speed·cos(angle))`. Fails when the slices have different
+/// lengths or are empty, and is `NAN` when all speeds are zero.
+pub fn mean_direction_weighted_by_speed(
+    angles: &[f64],
+    speeds: &[f64],
+) -> Result<f64, DirectionError> {
+    if angles.len() != speeds.len() {
+        return Err(DirectionError::LengthMismatch {
+            left: angles.len(),
+            right: speeds.len(),
+        });
+    }
+    if angles.is_empty() {
+        return Err(DirectionError::EmptyInput);
+    }
+    let mut measurements = DirectionMeasurementsSpeedWeighted::new();
+    for (&angle, &speed) in angles.iter().zip(speeds) {
+        measurements.add(angle, speed);
+    }
+    Ok(measurements.average_direction())
+}
+
+/// Accumulator of wind vectors, directions weighted by wind speed. This gives the vector mean
+/// direction used in meteorology, where calm periods barely influence the result, rather than
+/// the plain circular mean of [`WindDirectionMeasurements`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DirectionMeasurementsSpeedWeighted {
+    inner: WindDirectionMeasurements,
+}
+
+impl DirectionMeasurementsSpeedWeighted {
+    pub fn new() -> Self {
+        DirectionMeasurementsSpeedWeighted::default()
+    }
+
+    /// Adds a direction in degrees with the wind speed measured at the same time.
+    pub fn add(&mut self, angle: f64, speed: f64) {
+        self.inner.add_weighted_measurement(angle, speed);
+    }
+
+    /// The speed-weighted measurements, for statistics not forwarded by this type.
+    pub fn measurements(&self) -> &WindDirectionMeasurements {
+        &self.inner
+    }
+
+    pub fn average_direction(&self) -> f64 {
+        self.inner.average_direction()
+    }
+
+    /// Magnitude of the mean wind vector, `|Σ speed·(cos, sin)| / n`, which is at most the
+    /// scalar mean speed. `NAN` when empty.
+    pub fn vector_mean_speed(&self) -> f64 {
+        self.inner.sum_sin_rad().hypot(self.inner.sum_cos_rad()) / self.inner.count() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calm_directions_barely_count() {
+        let angles = [90.0, 180.0];
+        let mean = mean_direction_weighted_by_speed(&angles, &[10.0, 0.1]).unwrap();
+        assert!((mean - 90.57).abs() < 0.01);
+        let equal = mean_direction_weighted_by_speed(&angles, &[1.0, 1.0]).unwrap();
+        assert!((equal - 135.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            mean_direction_weighted_by_speed(&[10.0], &[1.0, 2.0]),
+            Err(DirectionError::LengthMismatch { left: 1, right: 2 })
+        );
+        assert_eq!(
+            mean_direction_weighted_by_speed(&[], &[]),
+            Err(DirectionError::EmptyInput)
+        );
+        assert!(
+            mean_direction_weighted_by_speed(&[10.0], &[0.0])
+                .unwrap()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn vector_mean_speed() {
+        let mut measurements = DirectionMeasurementsSpeedWeighted::new();
+        assert!(measurements.vector_mean_speed().is_nan());
+        measurements.add(0.0, 4.0);
+        measurements.add(180.0, 2.0);
+        assert!((measurements.vector_mean_speed() - 1.0).abs() < 1e-9);
+        assert!(measurements.average_direction().abs() < 1e-9);
+        assert_eq!(measurements.measurements().count(), 2);
+    }
+}
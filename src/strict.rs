@@ -0,0 +1,106 @@
+use crate::{DirectionError, WindDirectionMeasurements};
+
+/// Whether [`DirectionMeasurementsStrict`] accepts finite angles outside [0, 360] such as -10 or
+/// 370, which are otherwise harmless since only their sine and cosine are accumulated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllowOutOfRange(pub bool);
+
+/// Rejects non-finite angles and, unless allowed, angles outside [0, `full_circle`].
+pub(crate) fn validate_angle(
+    angle: f64,
+    full_circle: f64,
+    allow_out_of_range: AllowOutOfRange,
+) -> Result<(), DirectionError> {
+    if !angle.is_finite() {
+        return Err(DirectionError::NonFiniteAngle);
+    }
+    if !allow_out_of_range.0 && !(0.0..=full_circle).contains(&angle) {
+        return Err(DirectionError::AngleOutOfRange);
+    }
+    Ok(())
+}
+
+/// Accumulator that validates every measurement and rejects the ones that would silently corrupt
+/// the sums (NaN, infinite, and optionally out of range) instead of adding them.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DirectionMeasurementsStrict {
+    inner: WindDirectionMeasurements,
+    allow_out_of_range: AllowOutOfRange,
+}
+
+impl DirectionMeasurementsStrict {
+    pub fn new(allow_out_of_range: AllowOutOfRange) -> Self {
+        DirectionMeasurementsStrict {
+            inner: WindDirectionMeasurements::new(),
+            allow_out_of_range,
+        }
+    }
+
+    /// Adds a measurement in degrees, the accumulator is left unchanged on error.
+    pub fn add_measurement(&mut self, angle_degrees: f64) -> Result<(), DirectionError> {
+        validate_angle(angle_degrees, 360.0, self.allow_out_of_range)?;
+        self.inner.add_measurement(angle_degrees);
+        Ok(())
+    }
+
+    /// The validated measurements, for statistics not forwarded by this type.
+    pub fn measurements(&self) -> &WindDirectionMeasurements {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> WindDirectionMeasurements {
+        self.inner
+    }
+
+    pub fn average_direction(&self) -> f64 {
+        self.inner.average_direction()
+    }
+
+    pub fn standard_deviation(&self) -> f64 {
+        self.inner.standard_deviation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_non_finite() {
+        let mut strict = DirectionMeasurementsStrict::new(AllowOutOfRange(true));
+        strict.add_measurement(90.0).unwrap();
+        assert_eq!(
+            strict.add_measurement(f64::INFINITY),
+            Err(DirectionError::NonFiniteAngle)
+        );
+        assert_eq!(
+            strict.add_measurement(f64::NAN),
+            Err(DirectionError::NonFiniteAngle)
+        );
+        assert!((strict.average_direction() - 90.0).abs() < 1e-9);
+        assert_eq!(strict.measurements().count(), 1);
+
+        let mut lenient = WindDirectionMeasurements::new();
+        lenient.add_measurement(90.0);
+        lenient.add_measurement(f64::INFINITY);
+        assert!(lenient.average_direction().is_nan());
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut strict = DirectionMeasurementsStrict::new(AllowOutOfRange(false));
+        assert_eq!(strict.add_measurement(0.0), Ok(()));
+        assert_eq!(strict.add_measurement(360.0), Ok(()));
+        assert_eq!(
+            strict.add_measurement(370.0),
+            Err(DirectionError::AngleOutOfRange)
+        );
+        assert_eq!(
+            strict.add_measurement(-10.0),
+            Err(DirectionError::AngleOutOfRange)
+        );
+        let mut allowing = DirectionMeasurementsStrict::new(AllowOutOfRange(true));
+        assert_eq!(allowing.add_measurement(370.0), Ok(()));
+        assert!((allowing.average_direction() - 10.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,11 @@
+/// Deterministic uniform samples in [0, 1) from a 64-bit linear congruential generator, for tests
+/// that need irregular but reproducible input.
+pub(crate) fn lcg(seed: u64) -> impl FnMut() -> f64 {
+    let mut state = seed;
+    move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
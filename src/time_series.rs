@@ -0,0 +1,154 @@
+use crate::WindDirectionMeasurements;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Directions paired with their timestamps (in seconds or any other consistent unit), for
+/// statistics over time windows.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirectionTimeSeries {
+    // (timestamp, angle_degrees) in insertion order
+    measurements: Vec<(f64, f64)>,
+}
+
+impl DirectionTimeSeries {
+    pub fn new() -> Self {
+        DirectionTimeSeries::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.measurements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.measurements.is_empty()
+    }
+
+    /// Adds a measurement, timestamps do not need to be in order.
+    pub fn add(&mut self, timestamp: f64, angle_degrees: f64) {
+        self.measurements.push((timestamp, angle_degrees));
+    }
+
+    /// Accumulates the measurements with `start <= timestamp < end`.
+    pub fn slice_window(&self, start: f64, end: f64) -> WindDirectionMeasurements {
+        self.measurements
+            .iter()
+            .filter(|(timestamp, _)| (start..end).contains(timestamp))
+            .map(|&(_, angle_degrees)| angle_degrees)
+            .collect()
+    }
+
+    /// Fixed-interval aggregates, the `i`-th covering `[t0 + i·dt, t0 + (i + 1)·dt)` with `t0`
+    /// the earliest timestamp, up to the interval containing the latest one. Intervals without
+    /// measurements are empty accumulators and measurements with non-finite timestamps are
+    /// skipped. Returns an empty `Vec` when there are no finite timestamps, `dt` is not a
+    /// positive finite number or the intervals cannot be allocated.
+    pub fn resample(&self, dt: f64) -> Vec<WindDirectionMeasurements> {
+        if !(dt > 0.0 && dt.is_finite()) {
+            return Vec::new();
+        }
+        let finite = || {
+            self.measurements
+                .iter()
+                .filter(|(timestamp, _)| timestamp.is_finite())
+        };
+        let Some(first) = finite().map(|&(timestamp, _)| timestamp).reduce(f64::min) else {
+            return Vec::new();
+        };
+        let last = finite()
+            .map(|&(timestamp, _)| timestamp)
+            .fold(first, f64::max);
+        // the span of two finite timestamps can still overflow to infinity
+        let last_index = ((last - first) / dt).floor();
+        if last_index >= usize::MAX as f64 {
+            return Vec::new();
+        }
+        let Some(n_intervals) = (last_index as usize).checked_add(1) else {
+            return Vec::new();
+        };
+        let mut intervals = Vec::new();
+        if intervals.try_reserve_exact(n_intervals).is_err() {
+            return Vec::new();
+        }
+        intervals.resize(n_intervals, WindDirectionMeasurements::new());
+        for &(timestamp, angle_degrees) in finite() {
+            let index = (((timestamp - first) / dt).floor() as usize).min(n_intervals - 1);
+            intervals[index].add_measurement(angle_degrees);
+        }
+        intervals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series() -> DirectionTimeSeries {
+        let mut series = DirectionTimeSeries::new();
+        for (timestamp, angle) in [
+            (0.0, 350.0),
+            (5.0, 10.0),
+            (10.0, 90.0),
+            (15.0, 100.0),
+            (30.0, 200.0),
+            (12.0, 80.0),
+        ] {
+            series.add(timestamp, angle);
+        }
+        series
+    }
+
+    #[test]
+    fn time_window() {
+        let window = series().slice_window(5.0, 15.0);
+        let expected = WindDirectionMeasurements::from_values(&[10.0, 90.0, 80.0]);
+        assert_eq!(window, expected);
+        let first = series().slice_window(0.0, 10.0);
+        assert!(
+            first
+                .average_direction()
+                .min(360.0 - first.average_direction())
+                < 1e-9
+        );
+        assert!(series().slice_window(40.0, 50.0).is_empty());
+    }
+
+    #[test]
+    fn resample() {
+        let intervals = series().resample(10.0);
+        assert_eq!(intervals.len(), 4);
+        assert_eq!(
+            intervals[0],
+            WindDirectionMeasurements::from_values(&[350.0, 10.0])
+        );
+        assert_eq!(
+            intervals[1],
+            WindDirectionMeasurements::from_values(&[90.0, 100.0, 80.0])
+        );
+        assert!(intervals[2].is_empty());
+        assert_eq!(intervals[3].count(), 1);
+        assert!(DirectionTimeSeries::new().resample(10.0).is_empty());
+        assert!(series().resample(0.0).is_empty());
+    }
+
+    #[test]
+    fn resample_non_finite_timestamps() {
+        let mut with_infinity = series();
+        with_infinity.add(f64::INFINITY, 270.0);
+        with_infinity.add(f64::NAN, 270.0);
+        assert_eq!(with_infinity.resample(10.0), series().resample(10.0));
+
+        let mut only_infinite = DirectionTimeSeries::new();
+        only_infinite.add(f64::INFINITY, 270.0);
+        assert!(only_infinite.resample(10.0).is_empty());
+
+        let mut huge_span = DirectionTimeSeries::new();
+        huge_span.add(-f64::MAX, 10.0);
+        huge_span.add(f64::MAX, 20.0);
+        assert!(huge_span.resample(1.0).is_empty());
+        let mut too_many_intervals = DirectionTimeSeries::new();
+        too_many_intervals.add(0.0, 10.0);
+        too_many_intervals.add(1e300, 20.0);
+        assert!(too_many_intervals.resample(1.0).is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::WindDirectionMeasurements;
+
+/// Accumulator for irregularly spaced samples, each measurement is weighted by the time it
+/// represents so that dense bursts are not over-represented.
+///
+/// Weights follow the trapezoidal rule: the interval between two consecutive samples contributes
+/// half of its duration to each of them. The latest sample therefore only carries half of the
+/// previous interval until the next one arrives, and a lone sample has no weight at all.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TimeWeightedDirectionMeasurements {
+    inner: WindDirectionMeasurements,
+    // latest sample as (angle_degrees, timestamp_secs, weight so far)
+    pending: Option<(f64, f64, f64)>,
+}
+
+impl TimeWeightedDirectionMeasurements {
+    pub fn new() -> Self {
+        TimeWeightedDirectionMeasurements::default()
+    }
+
+    /// Builds the accumulator from `(angle_degrees, timestamp_secs)` pairs in time order.
+    pub fn from_iterator_with_timestamps<I: IntoIterator<Item = (f64, f64)>>(iter: I) -> Self {
+        let mut measurements = TimeWeightedDirectionMeasurements::new();
+        for (angle_degrees, timestamp_secs) in iter {
+            measurements.add_measurement_at_time(angle_degrees, timestamp_secs);
+        }
+        measurements
+    }
+
+    /// Adds a sample taken at `timestamp_secs`, samples are expected in time order and a
+    /// timestamp before the previous one counts as a zero-length interval.
+    pub fn add_measurement_at_time(&mut self, angle_degrees: f64, timestamp_secs: f64) {
+        let half_interval = match self.pending {
+            Some((pending_angle, pending_timestamp, pending_weight)) => {
+                let half_interval = (timestamp_secs - pending_timestamp).max(0.0) / 2.0;
+                self.inner
+                    .add_weighted_measurement(pending_angle, pending_weight + half_interval);
+                half_interval
+            }
+            None => 0.0,
+        };
+        self.pending = Some((angle_degrees, timestamp_secs, half_interval));
+    }
+
+    /// Time-weighted measurements including the latest sample with its current weight.
+    pub fn measurements(&self) -> WindDirectionMeasurements {
+        let mut measurements = self.inner;
+        if let Some((angle_degrees, _, weight)) = self.pending {
+            measurements.add_weighted_measurement(angle_degrees, weight);
+        }
+        measurements
+    }
+
+    pub fn average_direction(&self) -> f64 {
+        self.measurements().average_direction()
+    }
+
+    pub fn standard_deviation(&self) -> f64 {
+        self.measurements().standard_deviation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burst_is_not_over_represented() {
+        let isolated = TimeWeightedDirectionMeasurements::from_iterator_with_timestamps([
+            (0.0, 0.0),
+            (90.0, 10.0),
+            (0.0, 20.0),
+        ]);
+        let burst = TimeWeightedDirectionMeasurements::from_iterator_with_timestamps([
+            (0.0, 0.0),
+            (90.0, 10.0),
+            (90.0, 10.1),
+            (0.0, 20.0),
+        ]);
+        assert!((isolated.average_direction() - 45.0).abs() < 1e-9);
+        assert!((burst.average_direction() - isolated.average_direction()).abs() < 1.0);
+        assert!((burst.measurements().sum_weights() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn burst_samples_weigh_less_than_isolated_sample() {
+        let mut isolated = TimeWeightedDirectionMeasurements::new();
+        isolated.add_measurement_at_time(0.0, 0.0);
+        isolated.add_measurement_at_time(90.0, 10.0);
+        isolated.add_measurement_at_time(0.0, 20.0);
+        let isolated_weight = isolated.inner.sum_weights() - 5.0;
+        let mut burst = TimeWeightedDirectionMeasurements::new();
+        burst.add_measurement_at_time(0.0, 0.0);
+        burst.add_measurement_at_time(90.0, 10.0);
+        let before = burst.inner.sum_weights();
+        burst.add_measurement_at_time(90.0, 10.1);
+        let first_weight = burst.inner.sum_weights() - before;
+        burst.add_measurement_at_time(0.0, 20.0);
+        let second_weight = burst.inner.sum_weights() - before - first_weight;
+        assert!(first_weight < isolated_weight);
+        assert!(second_weight < isolated_weight);
+    }
+
+    #[test]
+    fn lone_sample_has_no_weight() {
+        let mut measurements = TimeWeightedDirectionMeasurements::new();
+        assert!(measurements.average_direction().is_nan());
+        measurements.add_measurement_at_time(45.0, 100.0);
+        assert!(measurements.average_direction().is_nan());
+        measurements.add_measurement_at_time(45.0, 101.0);
+        assert!((measurements.average_direction() - 45.0).abs() < 1e-9);
+    }
+}
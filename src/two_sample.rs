@@ -0,0 +1,198 @@
+use crate::{WindDirectionMeasurements, cast, special};
+use num_traits::Float;
+
+/// Watson-Williams test for equal mean directions of two samples, returns
+/// `(f_statistic, p_value)`.
+///
+/// `F = (1 + 3 / (8κ)) (N - 2) (R₁ + R₂ - R) / (N - R₁ - R₂)` with `R₁`, `R₂` and `R` the
+/// resultant lengths of each sample and of both combined, `N` the total count and `κ` the
+/// [maximum likelihood concentration](WindDirectionMeasurements::von_mises_kappa) of the pooled
+/// mean resultant length `(R₁ + R₂) / N`. The statistic follows an F distribution with (1, N - 2)
+/// degrees of freedom. Both samples are assumed to be von Mises with a common concentration,
+/// which should not be too small (roughly R̄ > 0.45).
+///
+/// Small p-values reject the hypothesis that both samples share the same mean direction. Returns
+/// `(NAN, NAN)` unless both samples have at least 10 measurements, all with unit weight.
+pub fn watson_williams_test<F: Float>(
+    a: &WindDirectionMeasurements<F>,
+    b: &WindDirectionMeasurements<F>,
+) -> (F, F) {
+    if a.count() < 10 || b.count() < 10 || !a.has_unit_weights() || !b.has_unit_weights() {
+        return (F::nan(), F::nan());
+    }
+    let resultant_length = |sum_sin_rad: F, sum_cos_rad: F| {
+        sum_sin_rad.hypot(sum_cos_rad).to_f64().unwrap_or(f64::NAN)
+    };
+    let resultant_a = resultant_length(a.sum_sin_rad(), a.sum_cos_rad());
+    let resultant_b = resultant_length(b.sum_sin_rad(), b.sum_cos_rad());
+    let resultant_combined = resultant_length(
+        a.sum_sin_rad() + b.sum_sin_rad(),
+        a.sum_cos_rad() + b.sum_cos_rad(),
+    );
+    let n = (a.count() + b.count()) as f64;
+    let kappa = special::von_mises_kappa((resultant_a + resultant_b) / n);
+    let correction = 1.0 + 3.0 / (8.0 * kappa);
+    let f_statistic = correction * (n - 2.0) * (resultant_a + resultant_b - resultant_combined)
+        / (n - resultant_a - resultant_b);
+    let p = special::f_distribution_sf(f_statistic, 1.0, n - 2.0);
+    (cast(f_statistic), cast(p))
+}
+
+/// Signed angle in degrees from the mean direction of `a` to that of `b`, in (-180, 180] and
+/// positive when `b` is clockwise from `a`. Computed from the two mean vectors, so no manual
+/// wrap-around handling is needed. `NAN` when either sample is empty.
+pub fn phase_difference<F: Float>(
+    a: &WindDirectionMeasurements<F>,
+    b: &WindDirectionMeasurements<F>,
+) -> F {
+    if a.sum_weights() == F::zero() || b.sum_weights() == F::zero() {
+        return F::nan();
+    }
+    let cross = a.sum_cos_rad() * b.sum_sin_rad() - a.sum_sin_rad() * b.sum_cos_rad();
+    let dot = a.sum_cos_rad() * b.sum_cos_rad() + a.sum_sin_rad() * b.sum_sin_rad();
+    let difference = cross.atan2(dot).to_degrees();
+    // atan2 gives -180 for a negative zero cross product
+    if difference == cast(-180.0) {
+        cast(180.0)
+    } else {
+        difference
+    }
+}
+
+// Concentrations beyond this are treated as this value, the integration grid has to resolve a
+// peak of width 1/√κ.
+const MAX_OVERLAP_KAPPA: f64 = 1e8;
+
+/// Overlap coefficient of the von Mises distributions fitted to two samples, the area under
+/// `min(f_a, f_b)`, in [0, 1]: 1 for identical distributions and close to 0 for concentrated
+/// distributions with distant means.
+///
+/// Each distribution uses the sample mean direction and the
+/// [maximum likelihood concentration](WindDirectionMeasurements::von_mises_kappa). The integral
+/// is evaluated with the midpoint rule on a grid fine enough for the more concentrated one,
+/// concentrations above 10⁸ are capped. `NAN` when either sample is empty.
+pub fn von_mises_overlap<F: Float>(
+    a: &WindDirectionMeasurements<F>,
+    b: &WindDirectionMeasurements<F>,
+) -> F {
+    let parameters = |measurements: &WindDirectionMeasurements<F>| {
+        let mean = measurements
+            .average_direction_radians()
+            .to_f64()
+            .unwrap_or(f64::NAN);
+        let kappa = measurements
+            .von_mises_kappa()
+            .to_f64()
+            .unwrap_or(f64::NAN)
+            .min(MAX_OVERLAP_KAPPA);
+        // normalizing constant ln(2π I0(κ))
+        (
+            mean,
+            kappa,
+            core::f64::consts::TAU.ln() + special::ln_bessel_i0(kappa),
+        )
+    };
+    let (mean_a, kappa_a, ln_norm_a) = parameters(a);
+    let (mean_b, kappa_b, ln_norm_b) = parameters(b);
+    if mean_a.is_nan() || mean_b.is_nan() {
+        return F::nan();
+    }
+    let steps = (1000.0 + 100.0 * kappa_a.max(kappa_b).sqrt()) as usize;
+    let step = core::f64::consts::TAU / steps as f64;
+    let overlap: f64 = (0..steps)
+        .map(|i| {
+            let theta = (i as f64 + 0.5) * step;
+            let ln_density_a = kappa_a * (theta - mean_a).cos() - ln_norm_a;
+            let ln_density_b = kappa_b * (theta - mean_b).cos() - ln_norm_b;
+            ln_density_a.min(ln_density_b).exp()
+        })
+        .sum::<f64>()
+        * step;
+    cast(overlap.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // deterministic spread of roughly ±30° around `center`
+    fn sample(center: f64, n: usize, phase: usize) -> WindDirectionMeasurements {
+        (0..n)
+            .map(|i| center + ((i * 7 + phase) % 13) as f64 * 5.0 - 30.0)
+            .collect()
+    }
+
+    #[test]
+    fn same_mean_direction() {
+        // two samples scattered within ±35° of 350°
+        let even = WindDirectionMeasurements::from_values(&[
+            335.0, 352.0, 8.0, 341.0, 0.0, 15.0, 347.0, 359.0, 326.0, 5.0, 12.0, 338.0,
+        ]);
+        let odd = WindDirectionMeasurements::from_values(&[
+            344.0, 3.0, 356.0, 329.0, 10.0, 350.0, 18.0, 337.0, 2.0, 348.0, 355.0, 9.0,
+        ]);
+        let (f_statistic, p) = watson_williams_test(&even, &odd);
+        assert!(f_statistic >= 0.0);
+        assert!(p > 0.5);
+    }
+
+    #[test]
+    fn orthogonal_mean_directions() {
+        let (f_statistic, p) = watson_williams_test(&sample(0.0, 20, 0), &sample(90.0, 20, 3));
+        assert!(f_statistic > 50.0);
+        assert!(p < 1e-6);
+    }
+
+    #[test]
+    fn phase_difference_of_shifted_sample() {
+        for base in [0.0, 100.0, 300.0, 350.0] {
+            let a = sample(base, 13, 0);
+            let shifted: WindDirectionMeasurements = (0..13)
+                .map(|i| base + ((i * 7) % 13) as f64 * 5.0 - 30.0 + 90.0)
+                .collect();
+            assert!((phase_difference(&a, &shifted) - 90.0).abs() < 1e-9);
+            assert!((phase_difference(&shifted, &a) + 90.0).abs() < 1e-9);
+        }
+        let north = WindDirectionMeasurements::from_values(&[0.0]);
+        let south = WindDirectionMeasurements::from_values(&[180.0]);
+        assert_eq!(phase_difference(&north, &south), 180.0);
+        assert_eq!(phase_difference(&south, &north), 180.0);
+        assert!(phase_difference(&north, &WindDirectionMeasurements::new()).is_nan());
+    }
+
+    #[test]
+    fn overlap_of_same_distribution() {
+        let a = sample(30.0, 13, 0);
+        let b = sample(30.0, 13, 5);
+        assert!((von_mises_overlap(&a, &b) - 1.0).abs() < 1e-6);
+        let identical = WindDirectionMeasurements::from_values(&[45.0; 3]);
+        assert!((von_mises_overlap(&identical, &identical) - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn overlap_of_orthogonal_distributions() {
+        let north = WindDirectionMeasurements::from_values(&[358.0, 0.0, 2.0, 1.0, 359.0]);
+        let east = WindDirectionMeasurements::from_values(&[88.0, 90.0, 92.0, 91.0, 89.0]);
+        assert!(von_mises_overlap(&north, &east) < 1e-6);
+        let wide = sample(0.0, 20, 0);
+        let overlap = von_mises_overlap(&wide, &sample(90.0, 20, 0));
+        assert!(overlap > 0.0 && overlap < 0.5);
+        assert!(von_mises_overlap(&north, &WindDirectionMeasurements::new()).is_nan());
+    }
+
+    #[test]
+    fn too_few_measurements() {
+        let (f_statistic, p) = watson_williams_test(&sample(0.0, 9, 0), &sample(0.0, 20, 0));
+        assert!(f_statistic.is_nan() && p.is_nan());
+    }
+
+    #[test]
+    fn weighted_sample() {
+        let mut weighted = sample(0.0, 20, 0);
+        weighted.add_weighted_measurement(10.0, 2.0);
+        let (f_statistic, p) = watson_williams_test(&weighted, &sample(90.0, 20, 3));
+        assert!(f_statistic.is_nan() && p.is_nan());
+        let (f_statistic, p) = watson_williams_test(&sample(90.0, 20, 3), &weighted);
+        assert!(f_statistic.is_nan() && p.is_nan());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{WindDirectionMeasurements, normalize_angle};
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Kuiper test for circular uniformity of angles in degrees, returns `(v_statistic, p_value)`.
+///
+/// `V = D⁺ + D⁻` is the sum of the largest deviations of the empirical distribution function
+/// above and below the uniform one, which unlike the Kolmogorov-Smirnov statistic does not depend
+/// on where the circle is cut. The p-value uses Kuiper's (1960) asymptotic distribution with
+/// Stephens' finite-sample correction `λ = (√n + 0.155 + 0.24/√n) V`, it is reliable for
+/// n ≥ 8 or so. Small p-values reject uniformity, `(NAN, NAN)` for empty input.
+pub fn kuiper_test(angles: &[f64]) -> (f64, f64) {
+    if angles.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+    let mut fractions: Vec<f64> = angles
+        .iter()
+        .map(|&angle| normalize_angle(angle) / 360.0)
+        .collect();
+    fractions.sort_by(f64::total_cmp);
+    let n = fractions.len() as f64;
+    let mut d_plus = f64::NEG_INFINITY;
+    let mut d_minus = f64::NEG_INFINITY;
+    for (i, &fraction) in fractions.iter().enumerate() {
+        d_plus = d_plus.max((i + 1) as f64 / n - fraction);
+        d_minus = d_minus.max(fraction - i as f64 / n);
+    }
+    let v = d_plus + d_minus;
+    let sqrt_n = n.sqrt();
+    let lambda = (sqrt_n + 0.155 + 0.24 / sqrt_n) * v;
+    (v, kuiper_tail_probability(lambda))
+}
+
+/// Angular dispersion index, the circular variance expected under uniformity (1) divided by the
+/// observed circular variance `1 - R̄`, i.e. `1 / (1 - R̄)`.
+///
+/// Values near 1 mean the angles are spread around the whole circle (or balanced between
+/// opposite directions), values well above 1 mean they are concentrated on one side. Angles that
+/// all coincide give a huge or infinite value. `NAN` for fewer than 2 angles.
+pub fn angular_dispersion_index(angles: &[f64]) -> f64 {
+    if angles.len() < 2 {
+        return f64::NAN;
+    }
+    1.0 / (1.0 - WindDirectionMeasurements::from_values(angles).concentration_index())
+}
+
+// P(V > λ) of the asymptotic Kuiper distribution, Numerical Recipes `probkp`.
+fn kuiper_tail_probability(lambda: f64) -> f64 {
+    // the series converges slowly and the probability is 1 to double precision here
+    if lambda < 0.4 {
+        return 1.0;
+    }
+    let mut sum = 0.0;
+    for j in 1..=100 {
+        let j_lambda_squared = (j as f64 * lambda).powi(2);
+        let term = 2.0 * (4.0 * j_lambda_squared - 1.0) * (-2.0 * j_lambda_squared).exp();
+        sum += term;
+        if term.abs() <= 1e-12 * sum.abs() {
+            break;
+        }
+    }
+    sum.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_sample() {
+        let angles: Vec<f64> = (0..36).map(|i| i as f64 * 10.0 + 5.0).collect();
+        let (v, p) = kuiper_test(&angles);
+        assert!(v < 0.1);
+        assert!(p > 0.9);
+    }
+
+    #[test]
+    fn identical_angles() {
+        let (v, p) = kuiper_test(&[45.0; 20]);
+        assert!((v - 1.0).abs() < 1e-9);
+        assert!(p < 1e-6);
+    }
+
+    #[test]
+    fn rotation_invariant() {
+        let angles = [10.0, 20.0, 35.0, 90.0, 100.0, 200.0, 210.0, 300.0];
+        let rotated: Vec<f64> = angles.iter().map(|angle| angle + 123.0).collect();
+        let (v, p) = kuiper_test(&angles);
+        let (rotated_v, rotated_p) = kuiper_test(&rotated);
+        assert!((v - rotated_v).abs() < 1e-9);
+        assert!((p - rotated_p).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_input() {
+        let (v, p) = kuiper_test(&[]);
+        assert!(v.is_nan() && p.is_nan());
+    }
+
+    #[test]
+    fn dispersion_index() {
+        let sweep: Vec<f64> = (0..360).map(|angle| angle as f64).collect();
+        assert!((angular_dispersion_index(&sweep) - 1.0).abs() < 1e-9);
+        let quadrant: Vec<f64> = (0..20).map(|i| 35.0 + i as f64).collect();
+        assert!(angular_dispersion_index(&quadrant) > 50.0);
+        assert!(angular_dispersion_index(&[0.0, 0.0]).is_infinite());
+        assert!(angular_dispersion_index(&[10.0]).is_nan());
+        assert!(angular_dispersion_index(&[]).is_nan());
+    }
+}
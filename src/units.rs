@@ -0,0 +1,160 @@
+use crate::WindDirectionMeasurements;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// An angle in degrees, for APIs where passing radians by mistake should not compile. Arithmetic
+/// keeps the unit and does not normalize, so `Degrees(360.0) + Degrees(20.0)` is 380°.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+/// An angle in radians, converts losslessly (up to rounding) into [`Degrees`] and back.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+impl From<f64> for Degrees {
+    fn from(degrees: f64) -> Self {
+        Degrees(degrees)
+    }
+}
+
+impl From<Degrees> for f64 {
+    fn from(degrees: Degrees) -> Self {
+        degrees.0
+    }
+}
+
+impl From<f64> for Radians {
+    fn from(radians: f64) -> Self {
+        Radians(radians)
+    }
+}
+
+impl From<Radians> for f64 {
+    fn from(radians: Radians) -> Self {
+        radians.0
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(radians: Radians) -> Self {
+        Degrees(radians.0.to_degrees())
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(degrees: Degrees) -> Self {
+        Radians(degrees.0.to_radians())
+    }
+}
+
+macro_rules! impl_unit_arithmetic {
+    ($unit:ident) => {
+        impl Add for $unit {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $unit(self.0 + other.0)
+            }
+        }
+
+        impl AddAssign for $unit {
+            fn add_assign(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $unit(self.0 - other.0)
+            }
+        }
+
+        impl SubAssign for $unit {
+            fn sub_assign(&mut self, other: Self) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl Neg for $unit {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $unit(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $unit {
+            type Output = Self;
+
+            fn mul(self, factor: f64) -> Self {
+                $unit(self.0 * factor)
+            }
+        }
+
+        impl Div<f64> for $unit {
+            type Output = Self;
+
+            fn div(self, divisor: f64) -> Self {
+                $unit(self.0 / divisor)
+            }
+        }
+    };
+}
+
+impl_unit_arithmetic!(Degrees);
+impl_unit_arithmetic!(Radians);
+
+impl WindDirectionMeasurements {
+    /// Same as [`Self::add_measurement`] with the unit checked by the type.
+    pub fn add_measurement_typed(&mut self, angle: Degrees) {
+        self.add_measurement(angle.0);
+    }
+
+    /// Same as [`Self::add_measurement_radians`] with the unit checked by the type.
+    pub fn add_measurement_radians_typed(&mut self, angle: Radians) {
+        self.add_measurement_radians(angle.0);
+    }
+
+    /// [`Self::average_direction`] tagged with its unit.
+    pub fn average_direction_typed(&self) -> Degrees {
+        Degrees(self.average_direction())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_keeps_unit() {
+        assert_eq!(Degrees(360.0) + Degrees(20.0), Degrees(380.0));
+        assert_eq!(Degrees(10.0) - Degrees(20.0), Degrees(-10.0));
+        assert_eq!(-Degrees(10.0) * 2.0, Degrees(-20.0));
+        assert_eq!(Radians(3.0) / 2.0, Radians(1.5));
+        let mut angle = Degrees(90.0);
+        angle += Degrees(45.0);
+        angle -= Degrees(15.0);
+        assert_eq!(angle, Degrees(120.0));
+        assert!(Degrees(10.0) < Degrees(20.0));
+    }
+
+    #[test]
+    fn conversions() {
+        let degrees: Degrees = 45.0.into();
+        let plain: f64 = degrees.into();
+        assert_eq!(plain, 45.0);
+        let radians = Radians::from(Degrees(180.0));
+        assert!((radians.0 - core::f64::consts::PI).abs() < 1e-15);
+        assert!((Degrees::from(radians).0 - 180.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn typed_measurements() {
+        let mut typed = WindDirectionMeasurements::new();
+        typed.add_measurement_typed(Degrees(350.0));
+        typed.add_measurement_radians_typed(Degrees(20.0).into());
+        let plain = WindDirectionMeasurements::from_values(&[350.0, 20.0]);
+        assert!((typed.average_direction_typed().0 - plain.average_direction()).abs() < 1e-9);
+    }
+}
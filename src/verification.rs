@@ -0,0 +1,161 @@
+use crate::{DirectionError, angular_difference_signed, angular_distance};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Root mean squared angular distance between forecast and observed directions in degrees,
+/// `√(Σ d² / n)` with `d` the [`angular_distance`] of each pair.
+pub fn circular_rmse(predicted: &[f64], observed: &[f64]) -> Result<f64, DirectionError> {
+    let sum_of_squares = sum_of_distances(predicted, observed, |distance| distance * distance)?;
+    Ok((sum_of_squares / predicted.len() as f64).sqrt())
+}
+
+/// Mean angular distance between forecast and observed directions in degrees.
+pub fn circular_mae(predicted: &[f64], observed: &[f64]) -> Result<f64, DirectionError> {
+    let sum = sum_of_distances(predicted, observed, |distance| distance)?;
+    Ok(sum / predicted.len() as f64)
+}
+
+fn sum_of_distances(
+    predicted: &[f64],
+    observed: &[f64],
+    transform: impl Fn(f64) -> f64,
+) -> Result<f64, DirectionError> {
+    if predicted.len() != observed.len() {
+        return Err(DirectionError::LengthMismatch {
+            left: predicted.len(),
+            right: observed.len(),
+        });
+    }
+    if predicted.is_empty() {
+        return Err(DirectionError::EmptyInput);
+    }
+    Ok(predicted
+        .iter()
+        .zip(observed)
+        .map(|(&predicted, &observed)| transform(angular_distance(predicted, observed)))
+        .sum())
+}
+
+/// Running verification scores of forecast against observed directions, without storing the
+/// pairs. Errors are `forecast - observed` the short way around, in degrees.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DirectionForecastVerifier {
+    count: u64,
+    sum_sin_error: f64,
+    sum_cos_error: f64,
+    sum_absolute_error: f64,
+    sum_squared_error: f64,
+}
+
+impl DirectionForecastVerifier {
+    pub fn new() -> Self {
+        DirectionForecastVerifier::default()
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn add_pair(&mut self, forecast_deg: f64, observed_deg: f64) {
+        let error = angular_difference_signed(forecast_deg, observed_deg);
+        let error_rad = error.to_radians();
+        self.count += 1;
+        self.sum_sin_error += error_rad.sin();
+        self.sum_cos_error += error_rad.cos();
+        self.sum_absolute_error += error.abs();
+        self.sum_squared_error += error * error;
+    }
+
+    /// Circular mean of the signed errors in (-180, 180], positive when forecasts are
+    /// clockwise of the observations. `NAN` when empty.
+    pub fn circular_bias(&self) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        self.sum_sin_error.atan2(self.sum_cos_error).to_degrees()
+    }
+
+    /// Same as the [`circular_rmse`] function over all pairs, `NAN` when empty.
+    pub fn circular_rmse(&self) -> f64 {
+        (self.sum_squared_error / self.count as f64).sqrt()
+    }
+
+    /// Same as the [`circular_mae`] function over all pairs, `NAN` when empty.
+    pub fn circular_mae(&self) -> f64 {
+        self.sum_absolute_error / self.count as f64
+    }
+
+    /// Mean squared error skill score against a reference forecast (e.g. persistence),
+    /// `1 - (rmse / reference_rmse)²`: 1 for a perfect forecast, 0 for no improvement over the
+    /// reference and negative when worse.
+    pub fn skill_score(&self, reference_rmse: f64) -> f64 {
+        1.0 - (self.circular_rmse() / reference_rmse).powi(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_directions() {
+        let directions = [0.0, 90.0, 359.0];
+        assert_eq!(circular_rmse(&directions, &directions), Ok(0.0));
+        assert_eq!(circular_mae(&directions, &directions), Ok(0.0));
+    }
+
+    #[test]
+    fn wraps_around_north() {
+        let expected = ((10.0f64 * 10.0 + 20.0 * 20.0) / 2.0).sqrt();
+        assert_eq!(circular_rmse(&[0.0, 350.0], &[10.0, 10.0]), Ok(expected));
+        assert_eq!(circular_mae(&[0.0, 350.0], &[10.0, 10.0]), Ok(15.0));
+    }
+
+    #[test]
+    fn perfect_forecast() {
+        let mut verifier = DirectionForecastVerifier::new();
+        for angle in [0.0, 90.0, 359.0, 180.0] {
+            verifier.add_pair(angle, angle);
+        }
+        assert_eq!(verifier.circular_bias(), 0.0);
+        assert_eq!(verifier.circular_rmse(), 0.0);
+        assert_eq!(verifier.circular_mae(), 0.0);
+        assert_eq!(verifier.skill_score(10.0), 1.0);
+    }
+
+    #[test]
+    fn verifier_matches_free_functions() {
+        let forecast = [0.0, 350.0, 100.0, 200.0];
+        let observed = [10.0, 10.0, 90.0, 170.0];
+        let mut verifier = DirectionForecastVerifier::new();
+        for (&forecast, &observed) in forecast.iter().zip(&observed) {
+            verifier.add_pair(forecast, observed);
+        }
+        let rmse = circular_rmse(&forecast, &observed).unwrap();
+        assert!((verifier.circular_rmse() - rmse).abs() < 1e-12);
+        assert!(
+            (verifier.circular_mae() - circular_mae(&forecast, &observed).unwrap()).abs() < 1e-12
+        );
+        // errors are -10, -20, 10 and 30, close to their arithmetic mean of 2.5
+        assert!((verifier.circular_bias() - 2.396_16).abs() < 1e-5);
+        assert!(verifier.skill_score(rmse).abs() < 1e-12);
+        assert!(verifier.skill_score(2.0 * rmse) > 0.0);
+    }
+
+    #[test]
+    fn empty_verifier() {
+        let verifier = DirectionForecastVerifier::new();
+        assert!(verifier.circular_bias().is_nan());
+        assert!(verifier.circular_rmse().is_nan());
+        assert!(verifier.circular_mae().is_nan());
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            circular_rmse(&[0.0, 10.0], &[0.0]),
+            Err(DirectionError::LengthMismatch { left: 2, right: 1 })
+        );
+        assert_eq!(circular_mae(&[], &[]), Err(DirectionError::EmptyInput));
+    }
+}